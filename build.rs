@@ -0,0 +1,76 @@
+//! Generates the example tests of every solution from its example manifest,
+//! i.e. `data/examples/<year>/<day>.json`. The tests are included by `advent_of_code::example_tests!`.
+use std::{env, fs, path::Path, str::FromStr};
+
+#[path = "src/template/manifest.rs"]
+#[allow(dead_code, unused_imports)]
+mod manifest;
+
+use manifest::Manifest;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+    println!("cargo:rerun-if-changed=data/examples");
+
+    let out_dir = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    for entry in fs::read_dir("src/bin").unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+
+        // NOTE: every binary gets a file, so that `example_tests!` compiles before a manifest exists.
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let tests = match name.split_once('-') {
+            Some((year, day)) => generate_tests(year, day),
+            None => String::new(),
+        };
+
+        fs::write(out_dir.join(format!("{name}.rs")), tests).unwrap();
+    }
+}
+
+/// Returns one test per example and part listed in the manifest of a day.
+fn generate_tests(year: &str, day: &str) -> String {
+    let manifest_path = format!("data/examples/{year}/{day}.json");
+    let Ok(contents) = fs::read_to_string(&manifest_path) else {
+        return String::new();
+    };
+
+    let manifest = match Manifest::from_str(&contents) {
+        Ok(manifest) => manifest,
+        Err(e) => panic!("could not parse \"{manifest_path}\": {e}"),
+    };
+
+    let mut tests = format!("// Generated from \"{manifest_path}\".\n");
+
+    for example in &manifest.examples {
+        let stem = example.file.trim_end_matches(".txt");
+        let name: String = stem
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        for (part, expected) in example.parts() {
+            let expected = match expected {
+                Some(answer) => format!("Some({answer:?}.to_string())"),
+                None => "None".into(),
+            };
+
+            tests.push_str(&format!(
+                r#"
+#[test]
+fn example_{name}_part_{part}() {{
+    let input = advent_of_code::template::read_example(PUZZLE, {file:?});
+    assert_eq!(SOLUTION.solve(&input, {part}), {expected});
+}}
+"#,
+                file = example.file,
+            ));
+        }
+    }
+
+    tests
+}
@@ -0,0 +1,293 @@
+/// Module that runs cellular automata on a [`Grid`], e.g. for puzzles that evolve a grid until it settles.
+///
+/// The next state of a cell is decided by a rule from its current state and the number of its neighbours
+/// that are counted as _live_. After the first generation, only cells next to a cell that changed are revisited,
+/// since all other cells are guaranteed to keep their state. When updating in place, a change is seen by the cells
+/// after it in reading order within the same generation, so those are revisited right away.
+use crate::{geometry::Pos, grid::Grid};
+use itertools::Either;
+use std::{collections::BTreeSet, mem};
+
+/// Which cells around a cell count as its neighbours.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// The four cells that share an edge with the cell.
+    Four,
+    /// The eight cells that share an edge or a corner with the cell.
+    #[default]
+    Eight,
+}
+
+/// When the cells of a generation see the new states of their neighbours.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UpdateMode {
+    /// All cells are updated at once, based on the states of the previous generation.
+    #[default]
+    Synchronous,
+    /// Cells are updated one by one in reading order, so they see the new states of cells updated before them.
+    InPlace,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AutomatonOptions {
+    pub neighbourhood: Neighbourhood,
+    pub update: UpdateMode,
+    /// Cells on an edge neighbour the cells on the opposite edge.
+    pub wrapping: bool,
+}
+
+pub struct Automaton<T, C, R> {
+    grid: Grid<T>,
+    options: AutomatonOptions,
+    /// Decides whether a neighbouring cell counts as live.
+    is_live: C,
+    /// Returns the next state of a cell, given its current state and the number of its live neighbours.
+    rule: R,
+    /// Cells to revisit in the next generation, and whether a cell is part of it already.
+    worklist: Vec<Pos>,
+    queued: Grid<bool>,
+    generation: usize,
+}
+
+impl<T, C, R> Automaton<T, C, R>
+where
+    T: PartialEq,
+    C: Fn(&T) -> bool,
+    R: Fn(&T, usize) -> T,
+{
+    pub fn new(grid: Grid<T>, options: AutomatonOptions, is_live: C, rule: R) -> Self {
+        Automaton {
+            worklist: grid.positions().collect(),
+            queued: grid.map(|_| true),
+            grid,
+            options,
+            is_live,
+            rule,
+            generation: 0,
+        }
+    }
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+    /// Returns the number of generations computed so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+    /// Computes the next generation and returns the number of cells that changed.
+    pub fn step(&mut self) -> usize {
+        let mut worklist = mem::take(&mut self.worklist);
+        worklist.sort_unstable_by_key(|pos| (pos.y, pos.x));
+        for pos in &worklist {
+            self.queued[*pos] = false;
+        }
+
+        let mut changed = vec![];
+        match self.options.update {
+            UpdateMode::Synchronous => {
+                let updates: Vec<(Pos, T)> = worklist
+                    .into_iter()
+                    .filter_map(|pos| {
+                        let next = self.next_state(pos);
+                        (next != self.grid[pos]).then_some((pos, next))
+                    })
+                    .collect();
+                for (pos, next) in updates {
+                    self.grid[pos] = next;
+                    changed.push(pos);
+                }
+            }
+            UpdateMode::InPlace => {
+                // cells are ordered by `(y, x)`, i.e. in reading order.
+                let mut pending: BTreeSet<(i32, i32)> =
+                    worklist.into_iter().map(|pos| (pos.y, pos.x)).collect();
+                while let Some((y, x)) = pending.pop_first() {
+                    let pos = Pos::new(x, y);
+                    let next = self.next_state(pos);
+                    if next != self.grid[pos] {
+                        self.grid[pos] = next;
+                        changed.push(pos);
+                        pending.extend(
+                            neighbours(&self.grid, pos, self.options)
+                                .map(|(pos, _)| (pos.y, pos.x))
+                                .filter(|&neighbour| neighbour > (y, x)),
+                        );
+                    }
+                }
+            }
+        }
+
+        for pos in &changed {
+            let neighbours = neighbours(&self.grid, *pos, self.options).map(|(pos, _)| pos);
+            for pos in neighbours.chain([*pos]) {
+                if !self.queued[pos] {
+                    self.queued[pos] = true;
+                    self.worklist.push(pos);
+                }
+            }
+        }
+
+        self.generation += 1;
+        changed.len()
+    }
+    /// Computes generations until no cell changes anymore.
+    /// Returns the number of changed cells of every generation, excluding the final one without changes.
+    pub fn run_to_fixpoint(&mut self) -> Vec<usize> {
+        let mut changes = vec![];
+        loop {
+            match self.step() {
+                0 => return changes,
+                n => changes.push(n),
+            }
+        }
+    }
+    fn next_state(&self, pos: Pos) -> T {
+        let live = neighbours(&self.grid, pos, self.options)
+            .filter(|(_, cell)| (self.is_live)(cell))
+            .count();
+        (self.rule)(&self.grid[pos], live)
+    }
+}
+
+fn neighbours<T>(
+    grid: &Grid<T>,
+    pos: Pos,
+    options: AutomatonOptions,
+) -> impl Iterator<Item = (Pos, &T)> {
+    match (options.neighbourhood, options.wrapping) {
+        (Neighbourhood::Four, false) => Either::Left(Either::Left(grid.neighbours4(pos))),
+        (Neighbourhood::Eight, false) => Either::Left(Either::Right(grid.neighbours8(pos))),
+        (Neighbourhood::Four, true) => Either::Right(Either::Left(grid.neighbours4_wrapping(pos))),
+        (Neighbourhood::Eight, true) => {
+            Either::Right(Either::Right(grid.neighbours8_wrapping(pos)))
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Automaton, AutomatonOptions, Neighbourhood, UpdateMode};
+    use crate::grid::Grid;
+
+    fn life(
+        input: &str,
+        wrapping: bool,
+    ) -> Automaton<bool, impl Fn(&bool) -> bool, impl Fn(&bool, usize) -> bool> {
+        let options = AutomatonOptions {
+            wrapping,
+            ..Default::default()
+        };
+        Automaton::new(
+            Grid::parse_with(input, |byte| byte == b'#'),
+            options,
+            |alive| *alive,
+            |alive, live| live == 3 || (*alive && live == 2),
+        )
+    }
+
+    fn render(grid: &Grid<bool>) -> String {
+        grid.map(|alive| if *alive { '#' } else { '.' }).to_string()
+    }
+
+    #[test]
+    fn steps_synchronously() {
+        let mut blinker = life(".....\n..#..\n..#..\n..#..\n.....\n", false);
+
+        assert_eq!(blinker.step(), 4);
+        assert_eq!(
+            render(blinker.grid()),
+            ".....\n.....\n.###.\n.....\n.....\n"
+        );
+        assert_eq!(blinker.step(), 4);
+        assert_eq!(
+            render(blinker.grid()),
+            ".....\n..#..\n..#..\n..#..\n.....\n"
+        );
+        assert_eq!(blinker.generation(), 2);
+    }
+
+    #[test]
+    fn wraps_around_edges() {
+        let mut blinker = life("#....\n#....\n#....\n", true);
+
+        blinker.step();
+        assert_eq!(render(blinker.grid()), "##..#\n##..#\n##..#\n");
+    }
+
+    #[test]
+    fn runs_to_fixpoint() {
+        let erode = |update| {
+            let options = AutomatonOptions {
+                update,
+                ..Default::default()
+            };
+            Automaton::new(
+                Grid::parse_with("@@@\n@@@\n@@@\n", |byte| byte == b'@'),
+                options,
+                |roll| *roll,
+                |roll, rolls| *roll && rolls >= 4,
+            )
+            .run_to_fixpoint()
+        };
+
+        assert_eq!(erode(UpdateMode::Synchronous), [4, 4, 1]);
+        assert_eq!(erode(UpdateMode::InPlace), [4, 5]);
+    }
+
+    #[test]
+    fn sees_earlier_changes_in_place() {
+        // a cell counts down from 3 to 1, and a 1 spreads to the empty cells next to it.
+        let spread = |update| {
+            let options = AutomatonOptions {
+                neighbourhood: Neighbourhood::Four,
+                update,
+                ..Default::default()
+            };
+            Automaton::new(
+                Grid::new("3000\n").map(|byte| byte - b'0'),
+                options,
+                |cell| *cell == 1,
+                |cell, live| match (*cell, live) {
+                    (0, 0) => 0,
+                    (0, _) => 1,
+                    (1, _) => 1,
+                    (cell, _) => cell - 1,
+                },
+            )
+            .run_to_fixpoint()
+        };
+
+        assert_eq!(spread(UpdateMode::Synchronous), [1, 1, 1, 1, 1]);
+        assert_eq!(spread(UpdateMode::InPlace), [1, 4]);
+    }
+
+    #[test]
+    fn counts_orthogonal_neighbours() {
+        let options = AutomatonOptions {
+            neighbourhood: Neighbourhood::Four,
+            ..Default::default()
+        };
+        // every cell takes the number of its live orthogonal neighbours.
+        let mut automaton = Automaton::new(
+            Grid::new("#.#\n...\n#.#\n").map(|byte| u8::from(*byte == b'#')),
+            options,
+            |cell| *cell == 1,
+            |_, live| live as u8,
+        );
+
+        assert_eq!(automaton.step(), 8);
+        assert_eq!(
+            automaton
+                .grid()
+                .map(|cell| (b'0' + cell) as char)
+                .to_string(),
+            "020\n202\n020\n"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,42 @@
+advent_of_code::solution!(1, year = 2025, parse = parse);
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input
+        .lines()
+        .map(|line| {
+            let n: i64 = line[1..].parse().unwrap();
+            if line.starts_with('L') { -n } else { n }
+        })
+        .collect()
+}
+
+pub fn part_one(input: &[i64]) -> Option<u64> {
+    let res = input
+        .iter()
+        .scan(50, |acc, n| {
+            *acc = (*acc + n).rem_euclid(100);
+            Some(*acc)
+        })
+        .filter(|n| *n == 0)
+        .count();
+    Some(res as u64)
+}
+
+pub fn part_two(input: &[i64]) -> Option<u64> {
+    let res = input
+        .iter()
+        .fold((50, 0), |(val, mut wrap_count), n| {
+            let res = val + n;
+            wrap_count += res.abs() / 100;
+            wrap_count += (val > 0 && res <= 0) as i64;
+            ((res % 100 + 100) % 100, wrap_count)
+        })
+        .1;
+    Some(res as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    // One test per example and part, see `data/examples/2025/01.json`.
+    advent_of_code::example_tests!();
+}
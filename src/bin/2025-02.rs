@@ -1,7 +1,7 @@
 use factor::factor::factor;
 use multimap::MultiMap;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2, year = 2025);
 
 // Parse into pairs representing the number ranges
 fn parse(input: &str) -> impl Iterator<Item = (u64, u64)> {
@@ -78,17 +78,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1227775554));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4174379265));
-    }
+    // One test per example and part, see `data/examples/2025/02.json`.
+    advent_of_code::example_tests!();
 }
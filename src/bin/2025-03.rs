@@ -1,4 +1,4 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(3, year = 2025);
 
 fn parse_ints(s: &str) -> Vec<u64> {
     s.as_bytes()
@@ -56,17 +56,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(357));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3121910778619));
-    }
+    // One test per example and part, see `data/examples/2025/03.json`.
+    advent_of_code::example_tests!();
 }
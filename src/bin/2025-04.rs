@@ -0,0 +1,39 @@
+advent_of_code::solution!(4, year = 2025);
+
+use advent_of_code::{Automaton, AutomatonOptions, Grid, UpdateMode};
+
+/// Parses the grid into cells that are `true` where a roll of paper (`@`) is.
+fn parse(input: &str) -> Grid<bool> {
+    Grid::parse_with(input, |byte| byte == b'@')
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let grid = parse(input);
+    let count = grid
+        .positions()
+        .filter(|&pos| grid[pos] && grid.neighbours8(pos).filter(|(_, roll)| **roll).count() < 4)
+        .count();
+    Some(count as u64)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let options = AutomatonOptions {
+        update: UpdateMode::InPlace,
+        ..Default::default()
+    };
+    // a roll stays as long as it is surrounded by at least four other rolls, every change removes one.
+    let mut automaton = Automaton::new(
+        parse(input),
+        options,
+        |roll| *roll,
+        |roll, rolls| *roll && rolls >= 4,
+    );
+    let removed: usize = automaton.run_to_fixpoint().into_iter().sum();
+    Some(removed as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    // One test per example and part, see `data/examples/2025/04.json`.
+    advent_of_code::example_tests!();
+}
@@ -1,6 +1,6 @@
 use rust_lapper::{Interval, Lapper};
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, year = 2025);
 
 fn parse_intervals(input: &str) -> Lapper<u64, u64> {
     let intervals = input
@@ -39,17 +39,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
-    }
+    // One test per example and part, see `data/examples/2025/05.json`.
+    advent_of_code::example_tests!();
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(6);
+advent_of_code::solution!(6, year = 2025);
 
 fn monoid_for(op: u8) -> (fn(u64, u64) -> u64, u64) {
     match op {
@@ -68,17 +68,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4277556));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3263823));
-    }
+    // One test per example and part, see `data/examples/2025/06.json`.
+    advent_of_code::example_tests!();
 }
@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(7, year = 2025);
 
 #[derive(Debug)]
 struct Problem {
@@ -73,17 +73,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(40));
-    }
+    // One test per example and part, see `data/examples/2025/07.json`.
+    advent_of_code::example_tests!();
 }
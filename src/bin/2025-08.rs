@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use std::collections::HashMap;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(8, year = 2025);
 
 #[derive(Hash, PartialEq, Eq, Clone)]
 struct Coord {
@@ -120,17 +120,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(25272));
-    }
+    // One test per example and part, see `data/examples/2025/08.json`.
+    advent_of_code::example_tests!();
 }
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use std::iter::once;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(9, year = 2025);
 
 type Coord = (i64, i64);
 
@@ -67,17 +67,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(50));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(24));
-    }
+    // One test per example and part, see `data/examples/2025/09.json`.
+    advent_of_code::example_tests!();
 }
@@ -1,7 +1,7 @@
-use std::collections::{HashSet, VecDeque};
+use advent_of_code::search;
 use z3::{Optimize, ast::Int};
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(10, year = 2025);
 
 #[derive(Debug)]
 struct Machine {
@@ -55,26 +55,14 @@ fn parse(input: &str) -> Vec<Machine> {
 }
 
 fn min_presses_for_lights(machine: &Machine) -> u64 {
-    let mut queue = VecDeque::new();
-    let mut visited = HashSet::new();
-
-    queue.push_back((0u32, 0u64)); // (current_lights, presses)
-    visited.insert(0u32);
-
-    while let Some((current_lights, presses)) = queue.pop_front() {
-        if current_lights == machine.lights {
-            return presses;
-        }
-        for &button in &machine.buttons {
-            let new_lights = current_lights ^ button;
-            if !visited.contains(&new_lights) {
-                visited.insert(new_lights);
-                queue.push_back((new_lights, presses + 1));
-            }
-        }
-    }
-
-    unreachable!("No solution found")
+    // every state is a set of lights, pressing a button toggles its lights.
+    search::bfs(
+        0u32,
+        |&lights| machine.buttons.iter().map(move |button| lights ^ button),
+        |&lights| lights == machine.lights,
+    )
+    .goal_distance()
+    .expect("No solution found")
 }
 
 fn min_presses_for_joltages(machine: &Machine) -> u64 {
@@ -144,17 +132,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(33));
-    }
+    // One test per example and part, see `data/examples/2025/10.json`.
+    advent_of_code::example_tests!();
 }
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, hash::Hash};
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(11, year = 2025);
 
 fn parse(input: &str) -> HashMap<&str, Vec<&str>> {
     let mut lookup = HashMap::new();
@@ -82,21 +82,6 @@ pub fn part_two(input: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(5));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(2));
-    }
+    // One test per example and part, see `data/examples/2025/11.json`.
+    advent_of_code::example_tests!();
 }
@@ -0,0 +1,268 @@
+/// Module that provides positions, offsets and directions on a two-dimensional plane.
+///
+/// Rows grow downwards, so [`Dir4::Up`] points towards `y - 1`. Coordinates are always
+/// passed as `x` first, then `y`, and are signed so that steps off the edge of a grid can be represented.
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position on the plane, e.g. a cell of a [`Grid`](crate::Grid).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// An offset between two positions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Pos {
+    pub const ORIGIN: Pos = Pos::new(0, 0);
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Pos { x, y }
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Vec2 { x, y }
+    }
+    /// Returns the number of orthogonal steps the offset spans.
+    pub fn manhattan(self) -> u32 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add<Vec2> for Pos {
+    type Output = Pos;
+    fn add(self, rhs: Vec2) -> Self::Output {
+        Pos::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign<Vec2> for Pos {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vec2> for Pos {
+    type Output = Pos;
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        Pos::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign<Vec2> for Pos {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Vec2;
+    fn sub(self, rhs: Pos) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+    fn add(self, rhs: Vec2) -> Self::Output {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+    fn neg(self) -> Self::Output {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Vec2 {
+    type Output = Vec2;
+    fn mul(self, rhs: i32) -> Self::Output {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// One of the four orthogonal directions, in clockwise order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// Rotates the direction by `steps` quarter turns, clockwise for positive steps.
+    pub fn rotate(self, steps: i32) -> Self {
+        Self::ALL[(self as i32 + steps).rem_euclid(4) as usize]
+    }
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+    pub fn turn_left(self) -> Self {
+        self.rotate(-1)
+    }
+    pub fn opposite(self) -> Self {
+        self.rotate(2)
+    }
+    /// Returns the offset of a single step in this direction.
+    pub const fn vec(self) -> Vec2 {
+        match self {
+            Dir4::Up => Vec2::new(0, -1),
+            Dir4::Right => Vec2::new(1, 0),
+            Dir4::Down => Vec2::new(0, 1),
+            Dir4::Left => Vec2::new(-1, 0),
+        }
+    }
+}
+
+/// One of the four orthogonal and four diagonal directions, in clockwise order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// Rotates the direction by `steps` eighth turns, clockwise for positive steps.
+    pub fn rotate(self, steps: i32) -> Self {
+        Self::ALL[(self as i32 + steps).rem_euclid(8) as usize]
+    }
+    /// Rotates the direction by a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+    /// Rotates the direction by a quarter turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+    /// Returns the offset of a single step in this direction.
+    pub const fn vec(self) -> Vec2 {
+        match self {
+            Dir8::Up => Vec2::new(0, -1),
+            Dir8::UpRight => Vec2::new(1, -1),
+            Dir8::Right => Vec2::new(1, 0),
+            Dir8::DownRight => Vec2::new(1, 1),
+            Dir8::Down => Vec2::new(0, 1),
+            Dir8::DownLeft => Vec2::new(-1, 1),
+            Dir8::Left => Vec2::new(-1, 0),
+            Dir8::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+impl From<Dir4> for Vec2 {
+    fn from(dir: Dir4) -> Self {
+        dir.vec()
+    }
+}
+
+impl From<Dir8> for Vec2 {
+    fn from(dir: Dir8) -> Self {
+        dir.vec()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Dir4, Dir8, Pos, Vec2};
+
+    #[test]
+    fn moves_positions() {
+        let mut pos = Pos::new(2, 3) + Dir4::Right.vec() * 3;
+        assert_eq!(pos, Pos::new(5, 3));
+
+        pos -= Vec2::new(1, 1);
+        assert_eq!(pos, Pos::new(4, 2));
+        assert_eq!(pos - Pos::ORIGIN, Vec2::new(4, 2));
+        assert_eq!((Pos::ORIGIN - pos).manhattan(), 6);
+        assert_eq!(-Dir4::Up.vec(), Dir4::Down.vec());
+    }
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+        assert_eq!(Dir4::Down.rotate(-5), Dir4::Right);
+
+        assert_eq!(Dir8::UpLeft.rotate(1), Dir8::Up);
+        assert_eq!(Dir8::UpRight.turn_right(), Dir8::DownRight);
+        assert_eq!(Dir8::DownLeft.opposite(), Dir8::UpRight);
+        assert_eq!(Dir8::from(Dir4::Left), Dir8::Left);
+
+        for dir in Dir8::ALL {
+            assert_eq!(dir.opposite().vec(), -dir.vec());
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
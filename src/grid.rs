@@ -0,0 +1,380 @@
+/// Module that provides a two-dimensional grid, the most common shape of puzzle inputs.
+///
+/// A grid is generic over its cell type. [`Grid::new`] keeps the raw bytes of the input, while
+/// [`Grid::parse_with`] turns every byte into a typed cell, e.g. an enum of the tiles of a puzzle.
+use crate::geometry::{Dir4, Dir8, Pos, Vec2};
+use itertools::Itertools;
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+/// A rectangular grid of cells, stored row by row. Defaults to a grid of the raw input bytes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T = u8> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+/// A grid of the raw bytes of an input, as created by [`Grid::new`].
+pub type ByteGrid = Grid<u8>;
+
+/// A cell type that corresponds to a single byte of the input.
+/// Implement it for the tiles of a puzzle to convert byte grids with `into()` and to print grids of them.
+pub trait Cell: Sized {
+    /// Converts a byte of the input to a cell, panics if the byte is not a valid cell.
+    fn from_byte(byte: u8) -> Self;
+    /// Converts the cell back to the byte it was parsed from.
+    fn to_byte(&self) -> u8;
+}
+
+impl Cell for char {
+    fn from_byte(byte: u8) -> Self {
+        byte as char
+    }
+    fn to_byte(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl Grid<u8> {
+    pub fn new(input: &str) -> Self {
+        Grid::parse_with(input, |byte| byte)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid from the lines of an input, converting every byte into a cell with `f`.
+    pub fn parse_with(input: &str, f: impl FnMut(u8) -> T) -> Self {
+        let lines = input.lines().collect::<Vec<_>>();
+        assert!(!lines.is_empty(), "a grid needs at least one row");
+        let width = lines[0].len();
+        assert!(
+            lines.iter().all(|line| line.len() == width),
+            "all rows of a grid need to have the same width"
+        );
+        Grid {
+            width,
+            height: lines.len(),
+            data: lines.into_iter().flat_map(str::bytes).map(f).collect(),
+        }
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    /// Returns whether a position lies within the grid.
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x >= 0 && (pos.x as usize) < self.width && pos.y >= 0 && (pos.y as usize) < self.height
+    }
+    /// Returns all positions of the grid in reading order, i.e. row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width as i32;
+        (0..self.height as i32)
+            .cartesian_product(0..width)
+            .map(|(y, x)| Pos::new(x, y))
+    }
+    /// Returns the cell at a position, or `None` if it lies outside the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.data[i])
+    }
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.data[i])
+    }
+    /// Returns the rows of the grid, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.width)
+    }
+    /// Returns a grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+}
+
+/// Offsets of the four cells that share an edge with a cell.
+const OFFSETS_4: [Vec2; 4] = [
+    Dir4::Up.vec(),
+    Dir4::Right.vec(),
+    Dir4::Down.vec(),
+    Dir4::Left.vec(),
+];
+
+/// Offsets of the eight cells that share an edge or a corner with a cell.
+const OFFSETS_8: [Vec2; 8] = [
+    Dir8::Up.vec(),
+    Dir8::UpRight.vec(),
+    Dir8::Right.vec(),
+    Dir8::DownRight.vec(),
+    Dir8::Down.vec(),
+    Dir8::DownLeft.vec(),
+    Dir8::Left.vec(),
+    Dir8::UpLeft.vec(),
+];
+
+/// Neighbours are visited clockwise, starting with the cell above.
+impl<T> Grid<T> {
+    /// Returns the up to four cells that share an edge with a position.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbours_at(pos, OFFSETS_4)
+    }
+    /// Returns the up to eight cells that share an edge or a corner with a position.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbours_at(pos, OFFSETS_8)
+    }
+    /// Like [`Grid::neighbours4`], but cells on an edge neighbour the cells on the opposite edge.
+    pub fn neighbours4_wrapping(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbours_wrapping_at(pos, OFFSETS_4)
+    }
+    /// Like [`Grid::neighbours8`], but cells on an edge neighbour the cells on the opposite edge.
+    pub fn neighbours8_wrapping(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbours_wrapping_at(pos, OFFSETS_8)
+    }
+    /// Calls `f` with every cell that shares an edge with a position, allowing it to modify them.
+    pub fn for_each_neighbour4_mut(&mut self, pos: Pos, f: impl FnMut(Pos, &mut T)) {
+        self.for_each_neighbour_mut_at(pos, OFFSETS_4, f);
+    }
+    /// Calls `f` with every cell that shares an edge or a corner with a position, allowing it to modify them.
+    pub fn for_each_neighbour8_mut(&mut self, pos: Pos, f: impl FnMut(Pos, &mut T)) {
+        self.for_each_neighbour_mut_at(pos, OFFSETS_8, f);
+    }
+    /// Wraps a position around the edges of the grid, as if the grid was repeated infinitely in every direction.
+    pub fn wrap(&self, pos: Pos) -> Pos {
+        Pos::new(
+            pos.x.rem_euclid(self.width as i32),
+            pos.y.rem_euclid(self.height as i32),
+        )
+    }
+    fn neighbours_at<const N: usize>(
+        &self,
+        pos: Pos,
+        offsets: [Vec2; N],
+    ) -> impl Iterator<Item = (Pos, &T)> {
+        offsets.into_iter().filter_map(move |offset| {
+            let neighbour = pos + offset;
+            self.get(neighbour).map(|cell| (neighbour, cell))
+        })
+    }
+    // NOTE: on grids narrower or shorter than three cells, a cell may neighbour itself or the same cell twice.
+    fn neighbours_wrapping_at<const N: usize>(
+        &self,
+        pos: Pos,
+        offsets: [Vec2; N],
+    ) -> impl Iterator<Item = (Pos, &T)> {
+        offsets.into_iter().map(move |offset| {
+            let neighbour = self.wrap(pos + offset);
+            (neighbour, &self[neighbour])
+        })
+    }
+    fn for_each_neighbour_mut_at<const N: usize>(
+        &mut self,
+        pos: Pos,
+        offsets: [Vec2; N],
+        mut f: impl FnMut(Pos, &mut T),
+    ) {
+        for offset in offsets {
+            let neighbour = pos + offset;
+            if let Some(cell) = self.get_mut(neighbour) {
+                f(neighbour, cell);
+            }
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+    fn index(&self, pos: Pos) -> &Self::Output {
+        match self.index_of(pos) {
+            Some(i) => &self.data[i],
+            None => panic!(
+                "position {pos} is outside of the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        match self.index_of(pos) {
+            Some(i) => &mut self.data[i],
+            None => panic!(
+                "position {pos} is outside of the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T: Cell> From<Grid<u8>> for Grid<T> {
+    fn from(grid: Grid<u8>) -> Self {
+        grid.map(|byte| T::from_byte(*byte))
+    }
+}
+
+impl<T: Cell> From<Grid<T>> for Grid<u8> {
+    fn from(grid: Grid<T>) -> Self {
+        grid.map(T::to_byte)
+    }
+}
+
+/// Renders a grid back to the text it was parsed from, one line per row.
+impl Display for Grid<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders a grid back to the text it was parsed from, one line per row.
+impl<T: Cell> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let line: Vec<u8> = row.iter().map(T::to_byte).collect();
+            writeln!(f, "{}", String::from_utf8_lossy(&line))?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Cell, Grid};
+    use crate::geometry::Pos;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Tile {
+        Empty,
+        Roll,
+    }
+
+    impl Cell for Tile {
+        fn from_byte(byte: u8) -> Self {
+            match byte {
+                b'.' => Tile::Empty,
+                b'@' => Tile::Roll,
+                _ => panic!("unexpected tile {}", byte as char),
+            }
+        }
+        fn to_byte(&self) -> u8 {
+            match self {
+                Tile::Empty => b'.',
+                Tile::Roll => b'@',
+            }
+        }
+    }
+
+    #[test]
+    fn parses_cells() {
+        let grid = Grid::parse_with("..@\n@@.\n", |byte| byte == b'@');
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[Pos::new(2, 0)]);
+        assert!(!grid[Pos::new(2, 1)]);
+        assert_eq!(
+            grid.map(|roll| u8::from(*roll)).rows().nth(1),
+            Some(&[1, 1, 0][..])
+        );
+    }
+
+    #[test]
+    fn converts_byte_grids() {
+        let grid: Grid<Tile> = Grid::new("..@\n@@.\n").into();
+        assert_eq!(grid[Pos::new(2, 0)], Tile::Roll);
+        assert_eq!(
+            grid.neighbours8(Pos::ORIGIN)
+                .filter(|(_, tile)| **tile == Tile::Roll)
+                .count(),
+            2
+        );
+
+        let bytes: Grid = grid.into();
+        assert_eq!(bytes, Grid::new("..@\n@@.\n"));
+    }
+
+    #[test]
+    fn renders_grids() {
+        let input = "..@\n@@.\n";
+        assert_eq!(Grid::new(input).to_string(), input);
+
+        let grid: Grid<Tile> = Grid::new(input).into();
+        assert_eq!(grid.to_string(), input);
+    }
+
+    #[test]
+    fn indexes_positions() {
+        let mut grid = Grid::new("abc\ndef\n");
+
+        assert_eq!(
+            grid.positions().map(|pos| grid[pos]).collect::<Vec<_>>(),
+            b"abcdef"
+        );
+        assert_eq!(grid.get(Pos::new(0, 1)), Some(&b'd'));
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, -1)), None);
+
+        grid[Pos::new(1, 1)] = b'x';
+        assert_eq!(grid.to_string(), "abc\ndxf\n");
+    }
+
+    #[test]
+    fn visits_neighbours() {
+        let mut grid = Grid::new("abc\ndef\nghi\n");
+        let cells = |neighbours: Vec<(Pos, &u8)>| -> Vec<u8> {
+            neighbours.into_iter().map(|(_, cell)| *cell).collect()
+        };
+
+        assert_eq!(cells(grid.neighbours4(Pos::new(2, 0)).collect()), b"fb");
+        assert_eq!(cells(grid.neighbours8(Pos::new(2, 0)).collect()), b"feb");
+        assert_eq!(
+            cells(grid.neighbours8(Pos::new(1, 1)).collect()),
+            b"bcfihgda"
+        );
+        assert_eq!(
+            grid.neighbours4(Pos::ORIGIN)
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>(),
+            [Pos::new(1, 0), Pos::new(0, 1)]
+        );
+
+        assert_eq!(
+            cells(grid.neighbours4_wrapping(Pos::new(2, 0)).collect()),
+            b"iafb"
+        );
+        assert_eq!(
+            cells(grid.neighbours8_wrapping(Pos::ORIGIN).collect()),
+            b"ghbedfci"
+        );
+        assert_eq!(grid.wrap(Pos::new(-1, 7)), Pos::new(2, 1));
+
+        grid.for_each_neighbour4_mut(Pos::new(1, 1), |_, cell| *cell = cell.to_ascii_uppercase());
+        grid.for_each_neighbour8_mut(Pos::ORIGIN, |pos, cell| {
+            if pos == Pos::new(1, 1) {
+                *cell = b'*';
+            }
+        });
+        assert_eq!(grid.to_string(), "aBc\nD*F\ngHi\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "same width")]
+    fn rejects_ragged_rows() {
+        Grid::new("..@\n@@\n");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
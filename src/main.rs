@@ -1,78 +1,170 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, read, scaffold, solve, submit, time, verify,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{Day, Puzzle, Year};
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{
+        Day, MultiOptions, Puzzle, Year, commands::time, runner::InputSource,
+    };
     use std::process;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: Puzzle,
         },
         Read {
-            day: Day,
+            puzzle: Puzzle,
         },
         Scaffold {
-            day: Day,
+            puzzle: Puzzle,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
-            day: Day,
+            puzzle: Puzzle,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            watch: bool,
+            input: InputSource,
+        },
+        Submit {
+            flush: bool,
         },
         All {
+            year: Year,
             release: bool,
+            options: MultiOptions,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            year: Year,
+            puzzle: Option<Puzzle>,
             store: bool,
+            compare: Option<f64>,
+            options: MultiOptions,
+        },
+        Verify {
+            all: bool,
+            year: Year,
+            puzzle: Option<Puzzle>,
+            release: bool,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Combines a day with the event year, making sure the day is part of that event.
+    fn puzzle(year: Year, day: Day) -> Result<Puzzle, String> {
+        Puzzle::new(year, day).ok_or_else(|| {
+            format!(
+                "day {day} is not part of the {year} event, expecting a day number between 1 and {}",
+                year.days()
+            )
+        })
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        let year = args
+            .opt_value_from_str("--year")?
+            .unwrap_or_else(Year::configured);
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year,
                 release: args.contains("--release"),
+                options: MultiOptions::from_args(&mut args)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+                let compare = (args.contains("--compare") || threshold.is_some())
+                    .then(|| threshold.unwrap_or(time::DEFAULT_THRESHOLD));
+                let options = MultiOptions::from_args(&mut args)?;
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    year,
+                    puzzle: args
+                        .opt_free_from_str()?
+                        .map(|day| puzzle(year, day))
+                        .transpose()?,
                     store,
+                    compare,
+                    options,
                 }
             }
+            Some("verify") => AppArguments::Verify {
+                all: args.contains("--all"),
+                release: args.contains("--release"),
+                year,
+                puzzle: args
+                    .opt_free_from_str()?
+                    .map(|day| puzzle(year, day))
+                    .transpose()?,
+            },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: puzzle(year, args.free_from_str()?)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: puzzle(year, args.free_from_str()?)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: puzzle(year, args.free_from_str()?)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            Some("solve") => {
+                // flags are parsed first, so that they may precede the day, e.g. `solve --watch 7`.
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let watch = args.contains("--watch");
+                let path: Option<String> = args.opt_value_from_str("--input")?;
+                let example = args.contains("--example");
+
+                let puzzle = puzzle(year, args.free_from_str()?)?;
+                // the example number is optional and follows the day, e.g. `solve 5 --example 2`.
+                let input = match path.as_deref() {
+                    Some("-") => InputSource::Stdin,
+                    Some(path) => InputSource::File(path.into()),
+                    None if example => InputSource::Example(args.opt_free_from_str()?),
+                    None => InputSource::Puzzle,
+                };
+
+                if input != InputSource::Puzzle && submit.is_some() {
+                    return Err("only answers for the puzzle input can be submitted.".into());
+                }
+                if watch && (dhat || submit.is_some()) {
+                    return Err("--watch can not be combined with --dhat or --submit.".into());
+                }
+                if watch && input == InputSource::Stdin {
+                    return Err("--watch can not read the input from stdin.".into());
+                }
+
+                AppArguments::Solve {
+                    puzzle,
+                    release,
+                    submit,
+                    dhat,
+                    watch,
+                    input,
+                }
+            }
+            Some("submit") => AppArguments::Submit {
+                flush: args.contains("--flush"),
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -102,38 +194,68 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                options,
+            } => all::handle(year, release, &options),
+            AppArguments::Time {
+                year,
+                puzzle,
+                all,
+                store,
+                compare,
+                options,
+            } => time::handle(year, puzzle, all, store, compare, &options),
+            AppArguments::Verify {
+                all,
+                year,
+                puzzle,
+                release,
+            } => verify::handle(year, puzzle, all, release),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
             AppArguments::Scaffold {
-                day,
+                puzzle,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
+                // the description is downloaded first, so that its examples end up in the scaffold.
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
                 }
+                let template = template.as_deref().unwrap_or(scaffold::DEFAULT_TEMPLATE);
+                scaffold::handle(puzzle, overwrite, template);
             }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                watch,
+                input,
+            } => {
+                if watch {
+                    solve::handle_watch(puzzle, release, &input);
+                } else {
+                    solve::handle(puzzle, release, dhat, submit, &input);
+                }
+            }
+            AppArguments::Submit { flush } => submit::handle(flush),
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match Day::today().and_then(|day| Puzzle::new(Year::configured(), day)) {
+                    Some(puzzle) => {
+                        download::handle(puzzle);
+                        scaffold::handle(puzzle, false, scaffold::DEFAULT_TEMPLATE);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
                             "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            the {}th of december. Please use `scaffold` with a specific day.",
+                            Year::configured().days()
                         );
                         process::exit(1)
                     }
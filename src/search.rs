@@ -0,0 +1,300 @@
+/// Module that finds shortest paths, either between the cells of a [`Grid`] or through arbitrary state spaces.
+///
+/// State spaces are described by a closure that returns the successors of a state, so states can be anything
+/// hashable, e.g. a position along with a direction. All searches stop as soon as they reach a state for which
+/// `is_goal` returns `true`; pass `|_| false` to explore every reachable state instead.
+use crate::{geometry::Pos, grid::Grid};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// The outcome of a search.
+#[derive(Clone, Debug)]
+pub struct SearchResult<S> {
+    /// The length of the shortest path to every state the search reached.
+    pub distances: HashMap<S, u64>,
+    /// The state preceding every reached state on its shortest path. The start has no predecessor.
+    pub predecessors: HashMap<S, S>,
+    /// The goal state the search stopped at, if it reached one.
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> SearchResult<S> {
+    fn new() -> Self {
+        SearchResult {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+    /// Returns the length of the shortest path to a state, or `None` if the search did not reach it.
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+    /// Returns the length of the shortest path to the goal.
+    pub fn goal_distance(&self) -> Option<u64> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+    /// Returns the shortest path from the start to a state, including both.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(predecessor) = self.predecessors.get(path.last().unwrap()) {
+            path.push(predecessor.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+    /// Returns the shortest path from the start to the goal, including both.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+}
+
+/// Breadth-first search, for state spaces where every step has the same cost.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::from([(start.clone(), 0)]);
+    result.distances.insert(start, 0);
+
+    while let Some((state, distance)) = queue.pop_front() {
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+        for next in successors(&state) {
+            if !result.distances.contains_key(&next) {
+                result.distances.insert(next.clone(), distance + 1);
+                result.predecessors.insert(next.clone(), state.clone());
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm, for state spaces where steps have different costs.
+/// Successors are returned along with the cost of the step that reaches them.
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// A* search, which explores states in the direction of the goal first.
+/// `heuristic` estimates the remaining cost to the goal. For the result to be correct, it must never overestimate
+/// that cost, and must not drop by more than the cost of a step, e.g. the manhattan distance on a grid.
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut result = SearchResult::new();
+    // tentative distances and predecessors, moved to the result once a state is settled.
+    let mut best: HashMap<S, (u64, Option<S>)> = HashMap::from([(start.clone(), (0, None))]);
+    // the heap refers to states by their index, so that states don't need to be ordered.
+    let mut states = vec![start.clone()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+
+    while let Some(Reverse((_, distance, index))) = heap.pop() {
+        let state = &states[index];
+        if result.distances.contains_key(state) || best[state].0 < distance {
+            continue;
+        }
+
+        let state = state.clone();
+        result.distances.insert(state.clone(), distance);
+        if let Some(predecessor) = best[&state].1.clone() {
+            result.predecessors.insert(state.clone(), predecessor);
+        }
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            if result.distances.contains_key(&next)
+                || best
+                    .get(&next)
+                    .is_some_and(|(known, _)| *known <= next_distance)
+            {
+                continue;
+            }
+            best.insert(next.clone(), (next_distance, Some(state.clone())));
+            heap.push(Reverse((
+                next_distance + heuristic(&next),
+                next_distance,
+                states.len(),
+            )));
+            states.push(next);
+        }
+    }
+
+    result
+}
+
+/// Breadth-first search between the orthogonally adjacent cells of a grid, only entering cells that are `passable`.
+pub fn grid_bfs<T>(
+    grid: &Grid<T>,
+    start: Pos,
+    passable: impl Fn(&T) -> bool,
+    is_goal: impl FnMut(&Pos) -> bool,
+) -> SearchResult<Pos> {
+    let passable = &passable;
+    bfs(
+        start,
+        move |&pos| {
+            grid.neighbours4(pos)
+                .filter(move |(_, cell)| passable(cell))
+                .map(|(pos, _)| pos)
+        },
+        is_goal,
+    )
+}
+
+/// Dijkstra's algorithm between the orthogonally adjacent cells of a grid.
+/// `cost` returns the cost of entering a cell, or `None` if the cell can't be entered.
+pub fn grid_dijkstra<T>(
+    grid: &Grid<T>,
+    start: Pos,
+    cost: impl Fn(&T) -> Option<u64>,
+    is_goal: impl FnMut(&Pos) -> bool,
+) -> SearchResult<Pos> {
+    let cost = &cost;
+    dijkstra(
+        start,
+        move |&pos| {
+            grid.neighbours4(pos)
+                .filter_map(move |(pos, cell)| cost(cell).map(|cost| (pos, cost)))
+        },
+        is_goal,
+    )
+}
+
+/// A* search between the orthogonally adjacent cells of a grid, towards the cell at `goal`.
+/// `cost` returns the cost of entering a cell, or `None` if the cell can't be entered.
+/// The search is guided by the manhattan distance, so every cell must cost at least `1` to enter.
+pub fn grid_astar<T>(
+    grid: &Grid<T>,
+    start: Pos,
+    goal: Pos,
+    cost: impl Fn(&T) -> Option<u64>,
+) -> SearchResult<Pos> {
+    let cost = &cost;
+    astar(
+        start,
+        move |&pos| {
+            grid.neighbours4(pos)
+                .filter_map(move |(pos, cell)| cost(cell).map(|cost| (pos, cost)))
+        },
+        |pos| u64::from((goal - *pos).manhattan()),
+        |pos| *pos == goal,
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{bfs, dijkstra, grid_astar, grid_bfs, grid_dijkstra};
+    use crate::{geometry::Pos, grid::Grid};
+
+    const MAZE: &str = "\
+S.#....
+.##.##.
+...#...
+.#...#E
+";
+
+    #[test]
+    fn searches_state_spaces() {
+        // reach 10 from 1 by adding one or doubling.
+        let result = bfs(1u32, |&n| [n + 1, n * 2], |&n| n == 10);
+        assert_eq!(result.goal_distance(), Some(4));
+        assert_eq!(result.path(), Some(vec![1, 2, 4, 5, 10]));
+
+        // doubling costs more than adding one, so it only pays off for larger numbers.
+        let result = dijkstra(1u32, |&n| [(n + 1, 1), (n * 2, 3)], |&n| n == 10);
+        assert_eq!(result.goal_distance(), Some(7));
+        assert_eq!(result.path(), Some(vec![1, 2, 3, 4, 5, 10]));
+
+        let result = bfs(1u32, |&n| (n < 4).then_some(n + 1), |_| false);
+        assert_eq!(result.goal, None);
+        assert_eq!(result.distances.len(), 4);
+        assert_eq!(result.path_to(&4), Some(vec![1, 2, 3, 4]));
+        assert_eq!(result.path_to(&5), None);
+    }
+
+    #[test]
+    fn searches_grids() {
+        let grid = Grid::new(MAZE);
+        let start = Pos::ORIGIN;
+        let goal = Pos::new(6, 3);
+
+        let result = grid_bfs(&grid, start, |cell| *cell != b'#', |pos| *pos == goal);
+        assert_eq!(result.goal_distance(), Some(11));
+
+        let path = result.path().unwrap();
+        assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+        assert!(
+            path.windows(2)
+                .all(|step| (step[1] - step[0]).manhattan() == 1 && grid[step[1]] != b'#')
+        );
+
+        let unreachable = grid_bfs(&grid, start, |cell| *cell == b'.', |pos| *pos == goal);
+        assert_eq!(unreachable.goal_distance(), None);
+        assert_eq!(unreachable.distances.len(), 19);
+    }
+
+    #[test]
+    fn searches_weighted_grids() {
+        let grid = Grid::new("1163\n1381\n2136\n").map(|byte| u64::from(byte - b'0'));
+        let goal = Pos::new(3, 2);
+        let cost = |risk: &u64| Some(*risk);
+
+        let result = grid_dijkstra(&grid, Pos::ORIGIN, cost, |pos| *pos == goal);
+        assert_eq!(result.goal_distance(), Some(13));
+        assert_eq!(
+            result.path(),
+            Some(vec![
+                Pos::new(0, 0),
+                Pos::new(0, 1),
+                Pos::new(0, 2),
+                Pos::new(1, 2),
+                Pos::new(2, 2),
+                Pos::new(3, 2)
+            ])
+        );
+
+        let result = grid_astar(&grid, Pos::ORIGIN, goal, cost);
+        assert_eq!(result.goal_distance(), Some(13));
+        assert_eq!(result.path().map(|path| path.len()), Some(6));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
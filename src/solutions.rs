@@ -0,0 +1,177 @@
+//! Runs every registered solution in a single process.
+//! Invoked by `cargo all`, `cargo time` and `cargo verify`, new days are registered here by `cargo scaffold`.
+//! Days with a timeout are run by invoking this binary again with `solve <day>`, so that they can be killed.
+
+use advent_of_code::template::Runner;
+use advent_of_code::template::commands::{all, time, verify};
+use advent_of_code::template::runner::InputSource;
+use args::{AppArguments, parse};
+use std::process;
+
+advent_of_code::register_solutions! {
+    solution_2025_01 => "bin/2025-01.rs",
+    solution_2025_02 => "bin/2025-02.rs",
+    solution_2025_03 => "bin/2025-03.rs",
+    solution_2025_04 => "bin/2025-04.rs",
+    solution_2025_05 => "bin/2025-05.rs",
+    solution_2025_06 => "bin/2025-06.rs",
+    solution_2025_07 => "bin/2025-07.rs",
+    solution_2025_08 => "bin/2025-08.rs",
+    solution_2025_09 => "bin/2025-09.rs",
+    solution_2025_10 => "bin/2025-10.rs",
+    solution_2025_11 => "bin/2025-11.rs",
+}
+
+mod args {
+    use advent_of_code::template::{
+        Day, MultiOptions, Puzzle, Year, commands::time, runner::RunOptions,
+    };
+
+    pub enum AppArguments {
+        All {
+            year: Year,
+            options: MultiOptions,
+        },
+        Time {
+            all: bool,
+            year: Year,
+            puzzle: Option<Puzzle>,
+            store: bool,
+            compare: Option<f64>,
+            options: MultiOptions,
+        },
+        Verify {
+            all: bool,
+            year: Year,
+            puzzle: Option<Puzzle>,
+        },
+        Solve {
+            puzzle: Puzzle,
+            options: RunOptions,
+        },
+    }
+
+    /// Combines a day with the event year, making sure the day is part of that event.
+    fn puzzle(year: Year, day: Day) -> Result<Puzzle, String> {
+        Puzzle::new(year, day).ok_or_else(|| {
+            format!(
+                "day {day} is not part of the {year} event, expecting a day number between 1 and {}",
+                year.days()
+            )
+        })
+    }
+
+    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+        let mut args = pico_args::Arguments::from_env();
+
+        let subcommand = args.subcommand()?;
+        let year = args
+            .opt_value_from_str("--year")?
+            .unwrap_or_else(Year::configured);
+
+        let app_args = match subcommand.as_deref() {
+            Some("all") => AppArguments::All {
+                year,
+                options: MultiOptions::from_args(&mut args)?,
+            },
+            Some("time") => {
+                let all = args.contains("--all");
+                let store = args.contains("--store");
+                let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+                let compare = (args.contains("--compare") || threshold.is_some())
+                    .then(|| threshold.unwrap_or(time::DEFAULT_THRESHOLD));
+                let options = MultiOptions::from_args(&mut args)?;
+
+                AppArguments::Time {
+                    all,
+                    year,
+                    puzzle: args
+                        .opt_free_from_str()?
+                        .map(|day| puzzle(year, day))
+                        .transpose()?,
+                    store,
+                    compare,
+                    options,
+                }
+            }
+            Some("verify") => AppArguments::Verify {
+                all: args.contains("--all"),
+                year,
+                puzzle: args
+                    .opt_free_from_str()?
+                    .map(|day| puzzle(year, day))
+                    .transpose()?,
+            },
+            Some("solve") => AppArguments::Solve {
+                options: RunOptions {
+                    timed: args.contains("--time"),
+                    submit: None,
+                    json: args.contains("--json"),
+                },
+                puzzle: puzzle(year, args.free_from_str()?)?,
+            },
+            Some(x) => return Err(format!("unknown command: {x}").into()),
+            None => return Err("no command specified.".into()),
+        };
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+        }
+
+        Ok(app_args)
+    }
+}
+
+fn main() {
+    match parse() {
+        Err(err) => {
+            eprintln!("Error: {err}");
+            process::exit(1);
+        }
+        Ok(args) => {
+            let registry = registry();
+            let runner = Runner::InProcess(&registry);
+
+            match args {
+                AppArguments::All { year, options } => {
+                    if !all::run(&runner, year, &options) {
+                        process::exit(1);
+                    }
+                }
+                AppArguments::Time {
+                    all,
+                    year,
+                    puzzle,
+                    store,
+                    compare,
+                    options,
+                } => {
+                    if !time::run(&runner, year, puzzle, all, store, compare, &options) {
+                        process::exit(1);
+                    }
+                }
+                AppArguments::Verify { all, year, puzzle } => {
+                    if !verify::run(&runner, year, puzzle, all) {
+                        process::exit(1);
+                    }
+                }
+                AppArguments::Solve { puzzle, options } => {
+                    let Some(solution) = registry.get(puzzle) else {
+                        eprintln!("Error: {puzzle} is not registered.");
+                        process::exit(1);
+                    };
+                    match InputSource::Puzzle.read(puzzle) {
+                        Ok(input) => {
+                            solution.run(&input, &options);
+                        }
+                        Err(e) => {
+                            eprintln!("Error: {e}");
+                            process::exit(1);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
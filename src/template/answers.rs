@@ -0,0 +1,218 @@
+/// Module that stores accepted answers, so that solutions can be verified after refactoring.
+use std::{collections::HashMap, fmt::Display, fs, io, path::PathBuf, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Puzzle, manifest::answer_from_json};
+
+/// The accepted answers of a puzzle, stored in `data/answers/<year>/<day>.json`.
+/// Answers are recorded on a successful submit, or can be filled in by hand.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    /// Reads the answers of a puzzle. If no answers have been recorded yet, returns empty answers.
+    pub fn read(puzzle: Puzzle) -> Result<Self, String> {
+        match fs::read_to_string(get_answers_path(puzzle)) {
+            Ok(contents) => Answers::from_str(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Writes the answers of a puzzle to disk.
+    pub fn store(&self, puzzle: Puzzle) -> Result<(), io::Error> {
+        let path = get_answers_path(puzzle);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
+        JsonValue::from(self).format_to(&mut file)
+    }
+
+    /// Records the accepted answer of a part and writes the answers to disk.
+    pub fn record(puzzle: Puzzle, part: u8, answer: &str) -> Result<(), String> {
+        let mut answers = Answers::read(puzzle)?;
+        answers.set(part, answer.into());
+        answers.store(puzzle).map_err(|e| e.to_string())
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_1 = Some(answer),
+            2 => self.part_2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.part_1.is_none() && self.part_2.is_none()
+    }
+
+    /// Checks the answer a solution returned for a part against the recorded answer.
+    pub fn check(&self, part: u8, answer: Option<&str>) -> Verdict {
+        match (self.get(part), answer) {
+            (None, _) => Verdict::Missing,
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), actual) => Verdict::Fail {
+                expected: expected.into(),
+                actual: actual.map(Into::into),
+            },
+        }
+    }
+}
+
+fn get_answers_path(puzzle: Puzzle) -> PathBuf {
+    PathBuf::from("data")
+        .join("answers")
+        .join(puzzle.year().to_string())
+        .join(format!("{}.json", puzzle.day()))
+}
+
+/// The outcome of checking a part against its recorded answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// No answer has been recorded for the part.
+    Missing,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "✔ pass"),
+            Verdict::Fail {
+                expected,
+                actual: Some(actual),
+            } => write!(f, "✖ fail (expected {expected}, got {actual})"),
+            Verdict::Fail {
+                expected,
+                actual: None,
+            } => write!(f, "✖ fail (expected {expected}, got nothing)"),
+            Verdict::Missing => write!(f, "? missing"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        for (key, answer) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                match answer {
+                    Some(answer) => JsonValue::String(answer.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answers to be a JSON object.")?;
+
+        let answer = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(value) => answer_from_json(value).map_err(|e| format!("answers.{key} {e}")),
+        };
+
+        Ok(Answers {
+            part_1: answer("part_1")?,
+            part_2: answer("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::from_str(r#"{ "part_1": "42", "part_2": null }"#).unwrap();
+        assert_eq!(answers.part_1.as_deref(), Some("42"));
+        assert_eq!(answers.part_2, None);
+    }
+
+    #[test]
+    fn parses_numeric_answers() {
+        let answers = Answers::from_str(r#"{ "part_1": 1234, "part_2": 3121910778619 }"#).unwrap();
+        assert_eq!(answers.part_1.as_deref(), Some("1234"));
+        assert_eq!(answers.part_2.as_deref(), Some("3121910778619"));
+
+        assert!(Answers::from_str(r#"{ "part_1": 9007199254740993 }"#).is_err());
+        assert!(Answers::from_str(r#"{ "part_1": 1e21 }"#).is_err());
+        let answers = Answers::from_str(r#"{ "part_1": "9007199254740993" }"#).unwrap();
+        assert_eq!(answers.part_1.as_deref(), Some("9007199254740993"));
+    }
+
+    #[test]
+    fn round_trips_answers() {
+        let answers = Answers {
+            part_1: Some("abc\ndef".into()),
+            part_2: None,
+        };
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::from_str(&json).unwrap(), answers);
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers {
+            part_1: Some("42".into()),
+            part_2: None,
+        };
+        assert_eq!(answers.check(1, Some("42")), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, Some("41")),
+            Verdict::Fail {
+                expected: "42".into(),
+                actual: Some("41".into())
+            }
+        );
+        assert_eq!(
+            answers.check(1, None),
+            Verdict::Fail {
+                expected: "42".into(),
+                actual: None
+            }
+        );
+        assert_eq!(answers.check(2, Some("1")), Verdict::Missing);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,422 @@
+/// Native client for the advent of code website.
+/// Used instead of aoc-cli whenever a session cookie is configured.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use ureq::Agent;
+
+use crate::template::{Puzzle, aoc_cli, html};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The advent of code maintainers ask automated tools to identify themselves.
+const USER_AGENT: &str = "github.com/TartanLlama/advent-of-code-2025 (advent_of_code template)";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    Request(ureq::Error),
+    BadStatus { url: String, status: u16 },
+    UnexpectedResponse(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus { url, status } => {
+                write!(f, "{url} responded with status {status}.")
+            }
+            AocClientError::UnexpectedResponse(url) => {
+                write!(f, "{url} responded with an unexpected page.")
+            }
+            AocClientError::IO(e) => write!(f, "could not write to data directory: {e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        AocClientError::Request(e)
+    }
+}
+
+/// HTTP client for the advent of code website, authenticated with a session cookie.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        AocClient {
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.trim().into(),
+            agent,
+        }
+    }
+
+    /// Creates a client from the environment, returns `None` if no session cookie is configured.
+    ///
+    /// The session cookie is read from `AOC_SESSION`, or else from `~/.adventofcode.session`.
+    /// `AOC_BASE_URL` overrides the website, e.g. to point the client at a local server.
+    pub fn from_env() -> Option<Self> {
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| fs::read_to_string(session_file_path()?).ok())
+            .filter(|session| !session.trim().is_empty())?;
+
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Some(AocClient::new(&base_url, &session))
+    }
+
+    /// Fetches the puzzle input.
+    pub fn fetch_input(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        self.get(&self.puzzle_url(puzzle, "/input"))
+    }
+
+    /// Fetches the puzzle description as markdown. Contains part two once part one is solved.
+    pub fn fetch_description(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        let url = self.puzzle_url(puzzle, "");
+        let page = self.get(&url)?;
+        let articles = html::articles(&page);
+
+        if articles.is_empty() {
+            return Err(AocClientError::UnexpectedResponse(url));
+        }
+
+        Ok(articles
+            .into_iter()
+            .map(html::to_markdown)
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    /// Submits an answer and returns the response message as markdown.
+    pub fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let url = self.puzzle_url(puzzle, "/answer");
+        let level = part.to_string();
+
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie())
+            .send_form([("level", level.as_str()), ("answer", answer)])?;
+
+        let status = response.status().as_u16();
+        if status != 200 {
+            return Err(AocClientError::BadStatus { url, status });
+        }
+
+        let page = response.body_mut().read_to_string()?;
+        match html::articles(&page).first() {
+            Some(article) => Ok(html::to_markdown(article)),
+            None => Err(AocClientError::UnexpectedResponse(url)),
+        }
+    }
+
+    /// Downloads input and description of a puzzle to the data directory.
+    pub fn download(&self, puzzle: Puzzle) -> Result<(), AocClientError> {
+        let input_path = aoc_cli::get_input_path(puzzle);
+        let puzzle_path = aoc_cli::get_puzzle_path(puzzle);
+
+        let input = self.fetch_input(puzzle)?;
+        let description = self.fetch_description(puzzle)?;
+
+        write_file(&input_path, &input)?;
+        write_file(&puzzle_path, &description)?;
+
+        println!("---");
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        Ok(())
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", &self.cookie())
+            .call()?;
+
+        let status = response.status().as_u16();
+        if status != 200 {
+            return Err(AocClientError::BadStatus {
+                url: url.into(),
+                status,
+            });
+        }
+
+        Ok(response.body_mut().read_to_string()?)
+    }
+
+    fn puzzle_url(&self, puzzle: Puzzle, path: &str) -> String {
+        format!(
+            "{}/{}/day/{}{path}",
+            self.base_url,
+            puzzle.year(),
+            puzzle.day().into_inner()
+        )
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn session_file_path() -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(".adventofcode.session"))
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), AocClientError> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).map_err(AocClientError::IO)?;
+    }
+    fs::write(path, contents).map_err(AocClientError::IO)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The way the template talks to the advent of code website.
+pub enum Backend {
+    Native(AocClient),
+    AocCli,
+}
+
+#[derive(Debug)]
+pub enum BackendError {
+    NotConfigured,
+    Native(AocClientError),
+    AocCli(aoc_cli::AocCommandError),
+}
+
+impl Display for BackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackendError::NotConfigured => write!(
+                f,
+                "no session cookie found. Set \"AOC_SESSION\" or write it to \"~/.adventofcode.session\". Alternatively, run \"cargo install aoc-cli\" to install aoc-cli."
+            ),
+            BackendError::Native(e) => write!(f, "failed to call advent of code: {e}"),
+            BackendError::AocCli(e) => write!(f, "failed to call aoc-cli: {e}"),
+        }
+    }
+}
+
+impl From<AocClientError> for BackendError {
+    fn from(e: AocClientError) -> Self {
+        BackendError::Native(e)
+    }
+}
+
+impl From<aoc_cli::AocCommandError> for BackendError {
+    fn from(e: aoc_cli::AocCommandError) -> Self {
+        BackendError::AocCli(e)
+    }
+}
+
+impl Display for Backend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Backend::Native(client) => write!(f, "{}", client.base_url),
+            Backend::AocCli => write!(f, "aoc-cli"),
+        }
+    }
+}
+
+impl Backend {
+    /// Uses the native client if a session cookie is configured, and falls back to aoc-cli.
+    pub fn detect() -> Result<Self, BackendError> {
+        if let Some(client) = AocClient::from_env() {
+            return Ok(Backend::Native(client));
+        }
+        if aoc_cli::check().is_ok() {
+            return Ok(Backend::AocCli);
+        }
+        Err(BackendError::NotConfigured)
+    }
+
+    /// Downloads input and description of a puzzle to the data directory.
+    pub fn download(&self, puzzle: Puzzle) -> Result<(), BackendError> {
+        match self {
+            Backend::Native(client) => client.download(puzzle)?,
+            Backend::AocCli => {
+                aoc_cli::download(puzzle)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Prints the puzzle description to the terminal.
+    pub fn read(&self, puzzle: Puzzle) -> Result<(), BackendError> {
+        match self {
+            Backend::Native(client) => {
+                let description = client.fetch_description(puzzle)?;
+                write_file(&aoc_cli::get_puzzle_path(puzzle), &description)?;
+                println!("{description}");
+            }
+            Backend::AocCli => {
+                aoc_cli::read(puzzle)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Submits an answer, prints the response and returns it.
+    pub fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<String, BackendError> {
+        match self {
+            Backend::Native(client) => {
+                let response = client.submit(puzzle, part, answer)?;
+                print!("{response}");
+                Ok(response)
+            }
+            Backend::AocCli => {
+                let output = aoc_cli::submit(puzzle, part, answer)?;
+                Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::{AocClient, AocClientError};
+    use crate::{puzzle, template::Outcome};
+
+    /// Starts a local stand-in for the website that answers a single request.
+    /// Returns its base url and a handle resolving to the raw request it received.
+    fn serve(status: u16, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut body_bytes = vec![0; content_length];
+            reader.read_exact(&mut body_bytes).unwrap();
+            request.push_str(&String::from_utf8(body_bytes).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 {status} OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_input_with_session_cookie() {
+        let (base_url, handle) = serve(200, "L68\nL30\n");
+        let client = AocClient::new(&base_url, "secret\n");
+
+        let input = client.fetch_input(puzzle!(2025, 1)).unwrap();
+        assert_eq!(input, "L68\nL30\n");
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("GET /2025/day/1/input HTTP/1.1\r\n"));
+        assert!(
+            request
+                .to_lowercase()
+                .contains("cookie: session=secret\r\n")
+        );
+    }
+
+    #[test]
+    fn fetches_description_as_markdown() {
+        let (base_url, handle) = serve(
+            200,
+            r#"<html><main><article class="day-desc"><h2>--- Day 1: Test ---</h2><p>Find the <em>answer</em>.</p></article></main></html>"#,
+        );
+        let client = AocClient::new(&base_url, "secret");
+
+        let description = client.fetch_description(puzzle!(2025, 1)).unwrap();
+        assert_eq!(
+            description,
+            "## --- Day 1: Test ---\n\nFind the *answer*.\n"
+        );
+        assert!(
+            handle
+                .join()
+                .unwrap()
+                .starts_with("GET /2025/day/1 HTTP/1.1\r\n")
+        );
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, handle) = serve(
+            200,
+            r#"<html><main><article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer.</p></article></main></html>"#,
+        );
+        let client = AocClient::new(&base_url, "secret");
+
+        let response = client.submit(puzzle!(2025, 12), 2, "1234").unwrap();
+        assert_eq!(
+            response,
+            "That's the right answer!  You are one gold star closer.\n"
+        );
+        assert_eq!(Outcome::from_response(&response), Some(Outcome::Correct));
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /2025/day/12/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (base_url, handle) = serve(400, "Puzzle inputs differ by user.");
+        let client = AocClient::new(&base_url, "expired");
+
+        match client.fetch_input(puzzle!(2025, 1)) {
+            Err(AocClientError::BadStatus { url, status }) => {
+                assert_eq!(url, format!("{base_url}/2025/day/1/input"));
+                assert_eq!(status, 400);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+        handle.join().unwrap();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,240 @@
+/// Benchmarking of solution parts, and the statistics reported for a benchmark run.
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use std::{cmp, fmt::Display};
+
+use tinyjson::JsonValue;
+
+/// Upper bound of warmup iterations executed before samples are recorded.
+const MAX_WARMUP_ITERATIONS: u128 = 1000;
+
+/// Statistics of a benchmark run.
+///
+/// Samples outside of `1.5` times the interquartile range are counted as outliers,
+/// they are still included in all other statistics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    /// Number of iterations executed before recording samples.
+    pub warmup: u128,
+    pub samples: u128,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes statistics of a set of samples, returns [`None`] if there are no samples.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn from_samples(samples: &[Duration], warmup: u128) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let count = sorted.len();
+        let nanos = |d: &Duration| d.as_nanos() as f64;
+
+        let mean = sorted.iter().map(nanos).sum::<f64>() / count as f64;
+
+        let variance = if count > 1 {
+            sorted
+                .iter()
+                .map(|d| (nanos(d) - mean).powi(2))
+                .sum::<f64>()
+                / (count - 1) as f64
+        } else {
+            0_f64
+        };
+
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+
+        let q1 = nanos(&percentile(&sorted, 25));
+        let q3 = nanos(&percentile(&sorted, 75));
+        let fence = 1.5 * (q3 - q1);
+
+        let outliers = sorted
+            .iter()
+            .map(nanos)
+            .filter(|x| *x < q1 - fence || *x > q3 + fence)
+            .count();
+
+        Some(Self {
+            warmup,
+            samples: count as u128,
+            mean: Duration::from_nanos(mean as u64),
+            median,
+            min: sorted[0],
+            p95: percentile(&sorted, 95),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+            outliers: outliers as u128,
+        })
+    }
+}
+
+/// Nearest-rank percentile of a sorted, non-empty list of samples.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.1?} ± {:.1?} @ {} samples; median {:.1?}, min {:.1?}, p95 {:.1?}, {} outliers",
+            self.mean, self.stddev, self.samples, self.median, self.min, self.p95, self.outliers
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Bench a function: approx. 1 second of execution time or 10 samples, whatever takes longer.
+/// Before recording samples, a tenth of the iterations is run to warm up caches and branch predictors.
+pub fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> BenchStats {
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+
+    let warmup_iterations = (bench_iterations / 10).clamp(1, MAX_WARMUP_ITERATIONS);
+
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input)));
+    }
+
+    let mut timers: Vec<Duration> = vec![];
+
+    for _ in 0..bench_iterations {
+        let timer = Instant::now();
+        black_box(func(black_box(input)));
+        timers.push(timer.elapsed());
+    }
+
+    BenchStats::from_samples(&timers, warmup_iterations)
+        .expect("benchmarks always record at least one sample")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("warmup".into(), JsonValue::Number(value.warmup as f64));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("mean_nanos".into(), nanos(value.mean));
+        map.insert("median_nanos".into(), nanos(value.median));
+        map.insert("min_nanos".into(), nanos(value.min));
+        map.insert("p95_nanos".into(), nanos(value.p95));
+        map.insert("stddev_nanos".into(), nanos(value.stddev));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected bench stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        Ok(BenchStats {
+            warmup: number("warmup")? as u128,
+            samples: number("samples")? as u128,
+            mean: duration("mean_nanos")?,
+            median: duration("median_nanos")?,
+            min: duration("min_nanos")?,
+            p95: duration("p95_nanos")?,
+            stddev: duration("stddev_nanos")?,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::BenchStats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn computes_stats() {
+        let stats = BenchStats::from_samples(&nanos(&[5, 1, 4, 2, 3]), 1).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.p95, Duration::from_nanos(5));
+        // sample standard deviation of 1..=5 is ~1.58.
+        assert_eq!(stats.stddev, Duration::from_nanos(1));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn computes_median_of_even_samples() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 40, 20, 30]), 1).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(25));
+    }
+
+    #[test]
+    fn detects_outliers() {
+        let mut samples = vec![100; 19];
+        samples.push(10_000);
+        let stats = BenchStats::from_samples(&nanos(&samples), 1).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.median, Duration::from_nanos(100));
+        assert_eq!(stats.p95, Duration::from_nanos(100));
+    }
+
+    #[test]
+    fn handles_single_and_empty_samples() {
+        let stats = BenchStats::from_samples(&nanos(&[7]), 0).unwrap();
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.p95, Duration::from_nanos(7));
+        assert!(BenchStats::from_samples(&[], 0).is_none());
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let stats = BenchStats::from_samples(&nanos(&[5, 1, 4, 2, 3, 100]), 2).unwrap();
+        let json = JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&json).unwrap(), stats);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
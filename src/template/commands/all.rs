@@ -1,5 +1,87 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::collections::HashSet;
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, MultiOptions, Puzzle, Runner, Year, all_puzzles,
+    registry::{self, run_solutions_binary},
+    run_multi::run_multi,
+    runner::PartStatus,
+};
+
+/// Run all solutions, exits with a non-zero status if a day failed.
+pub fn handle(year: Year, is_release: bool, options: &MultiOptions) {
+    let mut args = vec!["all".into(), "--year".into(), year.to_string()];
+    args.extend(options.to_args());
+
+    match run_solutions_binary(&args, is_release) {
+        Ok(status) if !status.success() => process::exit(status.code().unwrap_or(1)),
+        Ok(_) => {}
+        Err(registry::Error::BuildFailed) => {
+            eprintln!("Could not build the solutions binary, running each day on its own instead.");
+            if !run(&Runner::ChildProcess { is_release }, year, options) {
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to run solutions: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Run all solutions of a year with the given runner and print the status of every part.
+/// Returns `false` if a day failed, i.e. it could not be run or one of its parts did not return an answer.
+pub fn run(runner: &Runner, year: Year, options: &MultiOptions) -> bool {
+    let puzzles_to_run: HashSet<Puzzle> = all_puzzles(year).collect();
+    let report = run_multi(&puzzles_to_run, runner, options);
+
+    println!();
+    println!("{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("-------");
+
+    let mut statuses: Vec<PartStatus> = vec![];
+
+    for puzzle in &report.puzzles {
+        let Some(result) = report.result(*puzzle) else {
+            if report.failed.contains(puzzle) {
+                println!("{puzzle}  failed to run");
+            } else {
+                println!("{puzzle}  Part 1: {:<10}  Part 2: unsolved", "unsolved");
+                statuses.extend([PartStatus::Unsolved; 2]);
+            }
+            continue;
+        };
+
+        // parts that did not report a result, e.g. because the solution only declares part one, count as unsolved.
+        let [part_1, part_2] = [1, 2].map(|part| {
+            result
+                .parts
+                .iter()
+                .find(|r| r.part == part)
+                .map_or(PartStatus::Unsolved, |r| r.status)
+        });
+
+        println!(
+            "{puzzle}  Part 1: {:<10}  Part 2: {}",
+            part_1.to_string(),
+            part_2
+        );
+
+        statuses.push(part_1);
+        statuses.push(part_2);
+    }
+
+    let count = |status: PartStatus| statuses.iter().filter(|s| **s == status).count();
+
+    println!();
+    println!(
+        "Parts: {} ok, {} panicked, {} timed out, {} unsolved.",
+        count(PartStatus::Solved),
+        count(PartStatus::Panicked),
+        count(PartStatus::TimedOut),
+        count(PartStatus::Unsolved)
+    );
+    report.print_summary();
+
+    report.is_success()
 }
@@ -3,4 +3,6 @@ pub mod download;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod submit;
 pub mod time;
+pub mod verify;
@@ -1,17 +1,12 @@
 use std::process;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{Puzzle, aoc_client::Backend};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
-    }
+pub fn handle(puzzle: Puzzle) {
+    let result = Backend::detect().and_then(|backend| backend.read(puzzle));
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = result {
+        eprintln!("{e}");
         process::exit(1);
     };
 }
@@ -1,13 +1,18 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use tinyjson::JsonValue;
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use crate::template::{
+    Examples, Puzzle, Year, aoc_cli, examples, registry,
+    templates::{self, Placeholders},
+};
+
+pub use crate::template::templates::DEFAULT_TEMPLATE;
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -20,6 +25,9 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,10 +35,36 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Returns `true` if a file has contents that should not be replaced.
+fn has_contents(path: &str) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+pub fn handle(puzzle: Puzzle, overwrite: bool, template: &str) {
+    let (year, day) = (puzzle.year(), puzzle.day());
+    let input_path = aoc_cli::get_input_path(puzzle);
+    let module_path = format!("src/bin/{puzzle}.rs");
+
+    let template = match templates::load(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+
+    // NOTE: the description and input are only present if they were downloaded before scaffolding.
+    let description = fs::read_to_string(aoc_cli::get_puzzle_path(puzzle)).ok();
+    let input = fs::read_to_string(&input_path)
+        .ok()
+        .filter(|input| !input.is_empty());
+
+    let examples = description
+        .as_deref()
+        .map(Examples::parse)
+        .unwrap_or_default();
+    let placeholders =
+        Placeholders::new(puzzle, description.as_deref(), &examples, input.as_deref());
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,13 +74,14 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(placeholders.render(&template).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
+            if placeholders.drops_parser(&template) {
+                eprintln!(
+                    "The template has no %PARSER% placeholder, so the parser suggested for the input was left out."
+                );
+            }
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -54,26 +89,85 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    match registry::register(puzzle) {
+        Ok(true) => {
+            println!("Registered solution in \"{}\"", registry::SOLUTIONS_PATH);
         }
+        Ok(false) => {}
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+            eprintln!("Failed to register solution: {e}");
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    // NOTE: the input may already have been downloaded.
+    if !has_contents(&input_path) {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", &input_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    }
+
+    let example_files = examples.files(puzzle);
+
+    if example_files.is_empty() {
+        let example_path = examples::get_example_path(puzzle);
+        if !has_contents(&example_path) {
+            match create_file(&example_path) {
+                Ok(_) => {
+                    println!("Created empty example file \"{}\"", &example_path);
+                }
+                Err(e) => {
+                    eprintln!("Failed to create example file: {e}");
+                    process::exit(1);
+                }
+            }
+        }
+    }
+
+    for (example_path, contents) in example_files {
+        if has_contents(&example_path) && !overwrite {
+            println!("Kept existing example file \"{}\"", &example_path);
+            continue;
+        }
+        match create_file(&example_path).and_then(|mut file| file.write_all(contents.as_bytes())) {
+            Ok(()) => {
+                println!(
+                    "Created example file \"{}\" from the puzzle description",
+                    &example_path
+                );
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    let manifest_path = examples::get_manifest_path(puzzle);
+    if has_contents(&manifest_path) && !overwrite {
+        println!("Kept existing example manifest \"{}\"", &manifest_path);
+    } else {
+        let manifest = JsonValue::from(&examples.manifest(puzzle));
+        match create_file(&manifest_path).and_then(|mut file| manifest.format_to(&mut file)) {
+            Ok(()) => {
+                println!("Created example manifest \"{}\"", &manifest_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example manifest: {e}");
+                process::exit(1);
+            }
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if year == Year::configured() {
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    } else {
+        println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+    }
 }
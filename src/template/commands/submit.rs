@@ -0,0 +1,48 @@
+use std::process;
+
+use crate::template::aoc_client::Backend;
+use crate::template::submit::{Queue, flush};
+
+/// List queued submissions, or submit them with `flush`.
+/// Exits with a non-zero status if submissions remain queued after flushing.
+pub fn handle(should_flush: bool) {
+    let queue = Queue::read().unwrap_or_else(|e| {
+        eprintln!("Could not read queued submissions: {e}");
+        process::exit(1);
+    });
+
+    if queue.entries.is_empty() {
+        println!("No queued submissions.");
+        return;
+    }
+
+    if !should_flush {
+        for queued in &queue.entries {
+            println!("{queued}");
+        }
+        println!("---");
+        println!("Run `cargo submit --flush` to submit them.");
+        return;
+    }
+
+    let backend = Backend::detect().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    let remaining = flush(&backend, &queue);
+
+    if let Err(e) = remaining.store() {
+        eprintln!("failed to update queued submissions: {e}");
+        process::exit(1);
+    }
+
+    if !remaining.entries.is_empty() {
+        println!("---");
+        println!(
+            "{} submission(s) remain queued, run `cargo submit --flush` again later.",
+            remaining.entries.len()
+        );
+        process::exit(1);
+    }
+}
@@ -1,30 +1,102 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::compare::{compare as compare_timings, print_comparisons};
+use crate::template::registry::{self, run_solutions_binary};
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::timings::{TimingRun, Timings};
+use crate::template::{MultiOptions, Puzzle, Runner, Year, all_puzzles, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+pub use crate::template::compare::DEFAULT_THRESHOLD;
+
+/// Bench solutions, exits with a non-zero status if a day failed, or if `compare` is set and a stage regressed.
+/// `compare` holds the slowdown threshold in percent.
+/// Days run one after another unless `options.jobs` is greater than one, since benching days at the same time skews their timings.
+pub fn handle(
+    year: Year,
+    puzzle: Option<Puzzle>,
+    run_all: bool,
+    store: bool,
+    compare: Option<f64>,
+    options: &MultiOptions,
+) {
+    let mut args = vec!["time".into(), "--year".into(), year.to_string()];
+    if run_all {
+        args.push("--all".into());
+    }
+    if store {
+        args.push("--store".into());
+    }
+    if let Some(threshold) = compare {
+        args.push("--compare".into());
+        args.push("--threshold".into());
+        args.push(threshold.to_string());
+    }
+    args.extend(options.to_args());
+    if let Some(puzzle) = puzzle {
+        args.push(puzzle.day().to_string());
+    }
+
+    match run_solutions_binary(&args, true) {
+        Ok(status) if !status.success() => process::exit(status.code().unwrap_or(1)),
+        Ok(_) => {}
+        Err(registry::Error::BuildFailed) => {
+            eprintln!("Could not build the solutions binary, running each day on its own instead.");
+            let runner = Runner::ChildProcess { is_release: true };
+            if !run(&runner, year, puzzle, run_all, store, compare, options) {
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to run solutions: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Bench solutions of a year with the given runner, optionally storing the timings
+/// and comparing them with the stored ones.
+/// Returns `false` if a day failed or the comparison found a regression.
+pub fn run(
+    runner: &Runner,
+    year: Year,
+    puzzle: Option<Puzzle>,
+    run_all: bool,
+    store: bool,
+    compare: Option<f64>,
+    options: &MultiOptions,
+) -> bool {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
+    let puzzles_to_run = puzzle.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            // NOTE: comparing only makes sense for days that have been benched before.
+            if run_all || compare.is_some() {
+                all_puzzles(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                all_puzzles(year)
+                    .filter(|puzzle| !stored_timings.is_day_complete(*puzzle))
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |puzzle| HashSet::from([puzzle]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = MultiOptions {
+        timed: true,
+        ..options.clone()
+    };
+    let mut report = run_multi(&puzzles_to_run, runner, &options);
+    let timings = report.timings.take().unwrap();
+
+    let regressions = compare.map_or(0, |threshold| {
+        print_comparisons(&compare_timings(&stored_timings, &timings), threshold)
+    });
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
+        let mut merged_timings = stored_timings.merge(&timings);
+        merged_timings.record(TimingRun::now(timings.data));
         merged_timings.store_file().unwrap();
 
         println!();
@@ -37,4 +109,9 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    println!();
+    report.print_summary();
+
+    regressions == 0 && report.is_success()
 }
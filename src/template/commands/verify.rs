@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+use std::process;
+
+use crate::template::registry::{self, run_solutions_binary};
+use crate::template::run_multi::{MultiOptions, run_multi};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Answers, Puzzle, Runner, Verdict, Year, all_puzzles};
+
+/// Verify solutions, exits with a non-zero status if an answer does not match the recorded one.
+pub fn handle(year: Year, puzzle: Option<Puzzle>, run_all: bool, is_release: bool) {
+    let mut args = vec!["verify".into(), "--year".into(), year.to_string()];
+    if run_all {
+        args.push("--all".into());
+    }
+    if let Some(puzzle) = puzzle {
+        args.push(puzzle.day().to_string());
+    }
+
+    match run_solutions_binary(&args, is_release) {
+        Ok(status) if !status.success() => process::exit(status.code().unwrap_or(1)),
+        Ok(_) => {}
+        Err(registry::Error::BuildFailed) => {
+            eprintln!("Could not build the solutions binary, running each day on its own instead.");
+            if !run(&Runner::ChildProcess { is_release }, year, puzzle, run_all) {
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to run solutions: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Run solutions against their real inputs and check the answers against the recorded ones.
+/// Without a puzzle or `run_all`, only puzzles with recorded answers are verified.
+/// Returns `false` if an answer did not match.
+pub fn run(runner: &Runner, year: Year, puzzle: Option<Puzzle>, run_all: bool) -> bool {
+    let puzzles_to_run: HashSet<Puzzle> = puzzle.map_or_else(
+        || {
+            all_puzzles(year)
+                .filter(|puzzle| run_all || !read_answers(*puzzle).is_empty())
+                .collect()
+        },
+        |puzzle| HashSet::from([puzzle]),
+    );
+
+    if puzzles_to_run.is_empty() {
+        println!(
+            "No answers recorded for {year}. Submit a solution or add its answers to \"data/answers/{year}\" to verify it."
+        );
+        return true;
+    }
+
+    let report = run_multi(&puzzles_to_run, runner, &MultiOptions::default());
+
+    let mut puzzles: Vec<Puzzle> = puzzles_to_run.into_iter().collect();
+    puzzles.sort_unstable();
+
+    println!();
+    println!("{ANSI_BOLD}Verification{ANSI_RESET}");
+    println!("------------");
+
+    let mut verdicts: Vec<Verdict> = vec![];
+
+    for puzzle in puzzles {
+        let answers = read_answers(puzzle);
+        let result = report
+            .results
+            .iter()
+            .find(|(p, _)| *p == puzzle)
+            .map(|(_, result)| result);
+
+        let [part_1, part_2] = [1, 2].map(|part| {
+            let answer = result
+                .and_then(|result| result.parts.iter().find(|r| r.part == part))
+                .and_then(|r| r.answer.as_deref());
+            answers.check(part, answer)
+        });
+
+        println!(
+            "{puzzle}  Part 1: {:<32}  Part 2: {}",
+            part_1.to_string(),
+            part_2
+        );
+
+        verdicts.push(part_1);
+        verdicts.push(part_2);
+    }
+
+    let passed = verdicts.iter().filter(|v| **v == Verdict::Pass).count();
+    let missing = verdicts.iter().filter(|v| **v == Verdict::Missing).count();
+    let failed = verdicts.len() - passed - missing;
+
+    println!();
+    println!("{ANSI_BOLD}{passed} passed, {failed} failed, {missing} missing.{ANSI_RESET}");
+
+    failed == 0
+}
+
+fn read_answers(puzzle: Puzzle) -> Answers {
+    Answers::read(puzzle).unwrap_or_else(|e| {
+        eprintln!("Could not read answers of {puzzle}: {e}");
+        Answers::default()
+    })
+}
@@ -0,0 +1,208 @@
+/// Compares benchmark runs against stored timings to detect regressions.
+use std::time::Duration;
+
+use crate::template::timings::{Stage, Timings};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Puzzle};
+
+/// Default slowdown (in percent) beyond which a stage counts as regressed.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// The median execution time of a stage before and after a change.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub puzzle: Puzzle,
+    pub stage: Stage,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Comparison {
+    /// Relative change of the execution time in percent, positive values are slowdowns.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn change(&self) -> f64 {
+        let before = self.before.as_nanos() as f64;
+        let after = self.after.as_nanos() as f64;
+        if before == 0.0 {
+            return 0.0;
+        }
+        (after - before) / before * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Compares the medians of every benched stage in `current` with its most recent stored timing.
+/// Stages without a stored baseline are skipped.
+pub fn compare(stored: &Timings, current: &Timings) -> Vec<Comparison> {
+    current
+        .data
+        .iter()
+        .flat_map(|timing| {
+            Stage::ALL.into_iter().filter_map(move |stage| {
+                let after = timing.stage(stage)?.stats?.median;
+                let before = stored
+                    .last_stage_timing(timing.puzzle, stage)?
+                    .stats?
+                    .median;
+                Some(Comparison {
+                    puzzle: timing.puzzle,
+                    stage,
+                    before,
+                    after,
+                })
+            })
+        })
+        .collect()
+}
+
+/// Prints a comparison table and returns the number of regressions.
+pub fn print_comparisons(comparisons: &[Comparison], threshold: f64) -> usize {
+    println!();
+    println!("{ANSI_BOLD}Compared to stored timings (median, threshold {threshold}%){ANSI_RESET}");
+
+    if comparisons.is_empty() {
+        println!("No stored timings to compare against.");
+        return 0;
+    }
+
+    for comparison in comparisons {
+        let marker = if comparison.is_regression(threshold) {
+            "✖"
+        } else {
+            " "
+        };
+        println!(
+            "{marker} {} {:<6} {:>10.1?} -> {:>10.1?} ({:+.1}%)",
+            comparison.puzzle,
+            comparison.stage.to_string(),
+            comparison.before,
+            comparison.after,
+            comparison.change()
+        );
+    }
+
+    let regressions = comparisons
+        .iter()
+        .filter(|comparison| comparison.is_regression(threshold))
+        .count();
+
+    if regressions > 0 {
+        println!("\n{ANSI_BOLD}{regressions} regression(s) beyond {threshold}%.{ANSI_RESET}");
+    } else {
+        println!("\nNo regressions.");
+    }
+
+    regressions
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::compare;
+    use crate::{
+        puzzle,
+        template::{
+            BenchStats, Puzzle,
+            timings::{PartTiming, Stage, Timing, TimingRun, Timings},
+        },
+    };
+
+    fn part(median: u64) -> Option<PartTiming> {
+        Some(PartTiming {
+            duration: format!("{median}ns"),
+            stats: BenchStats::from_samples(&[Duration::from_nanos(median)], 1),
+        })
+    }
+
+    fn timing(puzzle: Puzzle, part_1: Option<PartTiming>, part_2: Option<PartTiming>) -> Timing {
+        Timing {
+            puzzle,
+            parse: None,
+            part_1,
+            part_2,
+            total_nanos: 0_f64,
+        }
+    }
+
+    fn run(date: &str, data: Vec<Timing>) -> TimingRun {
+        TimingRun {
+            commit: Some("abc1234".into()),
+            date: date.into(),
+            data,
+        }
+    }
+
+    #[test]
+    fn compares_against_last_run_per_part() {
+        let stored = Timings {
+            data: vec![],
+            history: vec![
+                run(
+                    "2025-12-01T00:00:00Z",
+                    vec![timing(puzzle!(2025, 1), part(100), part(1000))],
+                ),
+                run(
+                    "2025-12-02T00:00:00Z",
+                    vec![timing(puzzle!(2025, 1), part(200), None)],
+                ),
+            ],
+        };
+        let current = Timings {
+            data: vec![timing(puzzle!(2025, 1), part(210), part(1500))],
+            history: vec![],
+        };
+
+        let comparisons = compare(&stored, &current);
+        assert_eq!(comparisons.len(), 2);
+
+        assert_eq!(comparisons[0].stage, Stage::PartOne);
+        assert_eq!(comparisons[0].before, Duration::from_nanos(200));
+        assert!(!comparisons[0].is_regression(10.0));
+
+        assert_eq!(comparisons[1].stage, Stage::PartTwo);
+        assert_eq!(comparisons[1].before, Duration::from_nanos(1000));
+        assert_eq!(comparisons[1].change(), 50.0);
+        assert!(comparisons[1].is_regression(10.0));
+        assert!(!comparisons[1].is_regression(60.0));
+    }
+
+    #[test]
+    fn falls_back_to_latest_timings() {
+        let stored = Timings {
+            data: vec![timing(puzzle!(2025, 1), part(100), None)],
+            history: vec![],
+        };
+        let current = Timings {
+            data: vec![timing(puzzle!(2025, 1), part(50), None)],
+            history: vec![],
+        };
+
+        let comparisons = compare(&stored, &current);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].change(), -50.0);
+    }
+
+    #[test]
+    fn skips_stages_without_stats() {
+        let stored = Timings {
+            data: vec![timing(puzzle!(2025, 1), Some("1ms".into()), None)],
+            history: vec![],
+        };
+        let current = Timings {
+            data: vec![
+                timing(puzzle!(2025, 1), part(100), None),
+                timing(puzzle!(2025, 2), part(100), None),
+            ],
+            history: vec![],
+        };
+
+        assert!(compare(&stored, &current).is_empty());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
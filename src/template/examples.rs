@@ -0,0 +1,370 @@
+/// Module that extracts example inputs and their expected answers from puzzle descriptions.
+/// Descriptions are downloaded to `data/puzzles/<year>/<day>.md` by `cargo download`.
+use crate::template::{ExampleCase, Manifest, Puzzle};
+
+/// An example input and the answer the puzzle description gives for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answer: Option<String>,
+}
+
+/// The examples of both parts of a puzzle.
+///
+/// Part two usually reuses the example of part one. It only has an input of its own
+/// if its description introduces a new example.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub part_1: Option<Example>,
+    pub part_2: Option<Example>,
+}
+
+impl Examples {
+    /// Extracts the examples from a puzzle description in markdown.
+    ///
+    /// The example input of a part is the first code block that is introduced as an example,
+    /// and its answer is the last emphasized code span of the part, e.g. `` `*3*` ``.
+    pub fn parse(markdown: &str) -> Self {
+        let (part_one, part_two) = match markdown.find("--- Part Two ---") {
+            Some(index) => markdown.split_at(index),
+            None => (markdown, ""),
+        };
+
+        let part_1 = example_input(part_one, true).map(|input| Example {
+            input,
+            answer: last_answer(part_one),
+        });
+
+        let part_2 = last_answer(part_two).map(|answer| Example {
+            input: example_input(part_two, false)
+                .filter(|input| Some(input) != part_1.as_ref().map(|e| &e.input))
+                .or_else(|| part_1.as_ref().map(|e| e.input.clone()))
+                .unwrap_or_default(),
+            answer: Some(answer),
+        });
+
+        Examples { part_1, part_2 }
+    }
+
+    /// Returns `true` if part two has an example input that differs from the one of part one.
+    pub fn is_split(&self) -> bool {
+        match (&self.part_1, &self.part_2) {
+            (Some(one), Some(two)) => one.input != two.input,
+            (None, Some(_)) => true,
+            _ => false,
+        }
+    }
+
+    pub fn get(&self, part: u8) -> Option<&Example> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the example files to write along with their contents.
+    /// Split examples are written to `<day>-<part>.txt`, a shared example to `<day>.txt`.
+    pub fn files(&self, puzzle: Puzzle) -> Vec<(String, &str)> {
+        if self.is_split() {
+            [1, 2]
+                .into_iter()
+                .filter_map(|part| {
+                    let example = self.get(part)?;
+                    Some((get_example_part_path(puzzle, part), example.input.as_str()))
+                })
+                .collect()
+        } else {
+            self.part_1
+                .iter()
+                .map(|example| (get_example_path(puzzle), example.input.as_str()))
+                .collect()
+        }
+    }
+
+    /// Returns the example manifest that tests every part against its example.
+    /// Parts without a known answer are expected to return `None` until the manifest is filled in.
+    pub fn manifest(&self, puzzle: Puzzle) -> Manifest {
+        let day = puzzle.day();
+        let answer = |part: u8| Some(self.get(part).and_then(|example| example.answer.clone()));
+
+        let examples = if self.is_split() {
+            [1, 2]
+                .into_iter()
+                .filter(|&part| self.get(part).is_some())
+                .map(|part| ExampleCase {
+                    file: format!("{day}-{part}.txt"),
+                    part_1: (part == 1).then(|| answer(1)).flatten(),
+                    part_2: (part == 2).then(|| answer(2)).flatten(),
+                })
+                .collect()
+        } else {
+            vec![ExampleCase {
+                file: format!("{day}.txt"),
+                part_1: answer(1),
+                part_2: answer(2),
+            }]
+        };
+
+        Manifest { examples }
+    }
+}
+
+pub fn get_manifest_path(puzzle: Puzzle) -> String {
+    format!("data/examples/{}/{}.json", puzzle.year(), puzzle.day())
+}
+
+pub fn get_example_path(puzzle: Puzzle) -> String {
+    format!("data/examples/{}/{}.txt", puzzle.year(), puzzle.day())
+}
+
+pub fn get_example_part_path(puzzle: Puzzle, part: u8) -> String {
+    format!(
+        "data/examples/{}/{}-{part}.txt",
+        puzzle.year(),
+        puzzle.day()
+    )
+}
+
+/// Returns the first code block of a section that is introduced as an example.
+/// If `fallback` is set, returns the first code block if none is introduced as an example.
+fn example_input(section: &str, fallback: bool) -> Option<String> {
+    let blocks = code_blocks(section);
+
+    blocks
+        .iter()
+        .find(|(lead_in, _)| introduces_example(lead_in))
+        .or_else(|| blocks.first().filter(|_| fallback))
+        .map(|(_, block)| block.clone())
+}
+
+/// Phrases that announce a new example input rather than an illustration of a previous one.
+const EXAMPLE_PHRASES: [&str; 5] = [
+    "for example",
+    "following example",
+    "new example",
+    "different example",
+    "larger example",
+];
+
+/// Returns `true` if the last paragraph before a code block announces an example,
+/// e.g. "For example, suppose you have the following list:".
+fn introduces_example(lead_in: &str) -> bool {
+    let paragraph = lead_in
+        .trim_end()
+        .rsplit("\n\n")
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    paragraph.ends_with(':')
+        && !paragraph.contains("above")
+        && EXAMPLE_PHRASES
+            .iter()
+            .any(|phrase| paragraph.contains(phrase))
+}
+
+/// Returns the fenced code blocks of a section, each with the text that precedes it.
+fn code_blocks(section: &str) -> Vec<(&str, String)> {
+    let mut blocks = vec![];
+    let mut lead_in_start = 0;
+    let mut block: Option<(usize, String)> = None;
+    let mut offset = 0;
+
+    for line in section.split_inclusive('\n') {
+        let is_fence = line.trim_end().starts_with("```");
+
+        block = match (block, is_fence) {
+            (None, true) => Some((offset, String::new())),
+            (None, false) => None,
+            (Some((start, content)), true) => {
+                blocks.push((&section[lead_in_start..start], content));
+                lead_in_start = offset + line.len();
+                None
+            }
+            (Some((start, mut content)), false) => {
+                content.push_str(line.trim_end_matches(['\r', '\n']));
+                content.push('\n');
+                Some((start, content))
+            }
+        };
+
+        offset += line.len();
+    }
+
+    blocks
+}
+
+/// Returns the last emphasized code span of a section, ignoring code blocks.
+/// Both `` `*42*` `` and `` *`42`* `` are recognized.
+fn last_answer(section: &str) -> Option<String> {
+    let mut in_block = false;
+    let mut answer = None;
+
+    for line in section.lines() {
+        if line.trim_start().starts_with("```") {
+            in_block = !in_block;
+            continue;
+        }
+        if !in_block {
+            answer = emphasized_code(line)
+                .last()
+                .map(|value| value.to_string())
+                .or(answer);
+        }
+    }
+
+    answer
+}
+
+/// Returns the emphasized code spans of a line in order of appearance.
+fn emphasized_code(line: &str) -> Vec<&str> {
+    let mut spans = vec![];
+    let mut position = 0;
+
+    while let Some(start) = line[position..].find('`') {
+        let open = position + start;
+        let Some(len) = line[open + 1..].find('`') else {
+            break;
+        };
+        let close = open + 1 + len;
+        let content = &line[open + 1..close];
+
+        let value = content
+            .strip_prefix('*')
+            .and_then(|content| content.strip_suffix('*'))
+            .or_else(|| {
+                (line[..open].ends_with('*') && line[close + 1..].starts_with('*'))
+                    .then_some(content)
+            });
+
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            spans.push(value);
+        }
+        position = close + 1;
+    }
+
+    spans
+}
+
+/// Declares one test per example and part listed in the example manifest of the day,
+/// i.e. `data/examples/<year>/<day>.json`. The tests are generated by the build script.
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     advent_of_code::example_tests!();
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        #[allow(unused_imports)]
+        use super::*;
+
+        include!(concat!(
+            env!("OUT_DIR"),
+            "/example_tests/",
+            env!("CARGO_BIN_NAME"),
+            ".rs"
+        ));
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, Examples};
+    use crate::template::ExampleCase;
+
+    const DESCRIPTION: &str = "## --- Day 1: Test ---
+
+The dial starts by pointing at `50`.
+
+For example, suppose the attached document contained the following rotations:
+
+```
+L68
+L30
+```
+
+Following these rotations would cause the dial to move as follows:
+
+```
+- The dial starts by pointing at 50.
+```
+
+Because the dial points at `0` a total of three times, the password in this example is `*3*`.
+
+What's the actual password to open the door?
+
+## --- Part Two ---
+
+Following the same rotations as in the above example, the dial points at zero a few extra times:
+
+```
+- The dial starts by pointing at 50.
+```
+
+In this example, the dial points at `0` three times at the end of a rotation, plus three more times during a rotation; so, in this example, the new password would be *`6`*.
+";
+
+    fn example(input: &str, answer: &str) -> Option<Example> {
+        Some(Example {
+            input: input.into(),
+            answer: Some(answer.into()),
+        })
+    }
+
+    #[test]
+    fn parses_shared_examples() {
+        let examples = Examples::parse(DESCRIPTION);
+        assert_eq!(examples.part_1, example("L68\nL30\n", "3"));
+        assert_eq!(examples.part_2, example("L68\nL30\n", "6"));
+        assert!(!examples.is_split());
+    }
+
+    #[test]
+    fn parses_separate_part_two_examples() {
+        let description = DESCRIPTION.replace(
+            "Following the same rotations as in the above example, the dial points at zero a few extra times:",
+            "For example, consider this new list:\n\n```\nR5\n```",
+        );
+        let examples = Examples::parse(&description);
+        assert_eq!(examples.part_2, example("R5\n", "6"));
+        assert!(examples.is_split());
+    }
+
+    #[test]
+    fn parses_part_one_only() {
+        let description = &DESCRIPTION[..DESCRIPTION.find("## --- Part Two").unwrap()];
+        let examples = Examples::parse(description);
+        assert_eq!(examples.part_1, example("L68\nL30\n", "3"));
+        assert_eq!(examples.part_2, None);
+    }
+
+    #[test]
+    fn ignores_descriptions_without_examples() {
+        assert_eq!(
+            Examples::parse("## --- Day 1 ---\n\nNo `code` here.\n"),
+            Examples::default()
+        );
+    }
+
+    #[test]
+    fn creates_manifests_from_examples() {
+        let puzzle = crate::puzzle!(2025, 1);
+        let manifest = Examples::parse(DESCRIPTION).manifest(puzzle);
+        assert_eq!(
+            manifest.examples,
+            vec![ExampleCase {
+                file: "01.txt".into(),
+                part_1: Some(Some("3".into())),
+                part_2: Some(Some("6".into())),
+            }]
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,148 @@
+//! Minimal HTML handling for pages of the advent of code website.
+//! Only supports the small subset of HTML used in puzzle descriptions and submission responses.
+
+/// Returns the inner HTML of every `<article>` element of a page.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(len) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + len]);
+        rest = &rest[content_start + len..];
+    }
+
+    articles
+}
+
+/// Converts HTML to markdown.
+///
+/// Headings, paragraphs, lists, links, `<code>` and `<em>` are converted to their markdown
+/// equivalents. `<pre>` blocks become fenced code blocks. Other tags are dropped.
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut hrefs: Vec<Option<String>> = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            out.push_str(&decode_entities(rest));
+            break;
+        };
+
+        let text = &rest[..tag_start];
+        // whitespace between block elements is not part of the content.
+        if in_pre || !(out.ends_with('\n') && text.trim().is_empty()) {
+            out.push_str(&decode_entities(text));
+        }
+
+        let Some(tag_len) = rest[tag_start..].find('>') else {
+            out.push_str(&decode_entities(&rest[tag_start..]));
+            break;
+        };
+
+        let tag = &rest[tag_start + 1..tag_start + tag_len];
+        rest = &rest[tag_start + tag_len + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), is_closing) {
+            ("h1" | "h2" | "h3", false) => out.push_str("## "),
+            ("h1" | "h2" | "h3" | "p" | "ul" | "ol", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            // NOTE: markdown does not support formatting inside of code blocks.
+            ("code" | "em", _) if in_pre => {}
+            ("code", _) => out.push('`'),
+            ("em", _) => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li" | "br", _) => out.push('\n'),
+            ("a", false) => {
+                out.push('[');
+                hrefs.push(attribute(tag, "href"));
+            }
+            ("a", true) => match hrefs.pop().flatten() {
+                Some(href) => out.push_str(&format!("]({href})")),
+                None => out.push(']'),
+            },
+            _ => {}
+        }
+    }
+
+    out.trim().to_string() + "\n"
+}
+
+/// Returns the value of an attribute of an opening tag, e.g. `href` of `a href="/2025"`.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let len = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + len]))
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{articles, to_markdown};
+
+    #[test]
+    fn extracts_articles() {
+        let html = r#"<main><article class="day-desc"><h2>--- Day 1 ---</h2></article><p>You can also</p><article class="day-desc"><p>Part two</p></article></main>"#;
+        assert_eq!(
+            articles(html),
+            vec!["<h2>--- Day 1 ---</h2>", "<p>Part two</p>"]
+        );
+    }
+
+    #[test]
+    fn converts_descriptions() {
+        let html = "<h2>--- Day 1: Test ---</h2><p>For <a href=\"/2025/about\">example</a>:</p>\n<pre><code>L68\nL30 &lt;- <em>here</em>\n</code></pre>\n<p>The answer is <code><em>3</em></code>.</p><ul><li>one</li><li>two</li></ul>";
+        assert_eq!(
+            to_markdown(html),
+            "## --- Day 1: Test ---\n\nFor [example](/2025/about):\n\n```\nL68\nL30 <- here\n```\n\nThe answer is `*3*`.\n\n- one\n- two\n"
+        );
+    }
+
+    #[test]
+    fn converts_submission_responses() {
+        let html = r#"<p>That's not the right answer; your answer is too high.  Please wait one minute before trying again. <a href="/2025/day/1">[Return to Day 1]</a></p>"#;
+        assert_eq!(
+            to_markdown(html),
+            "That's not the right answer; your answer is too high.  Please wait one minute before trying again. [[Return to Day 1]](/2025/day/1)\n"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
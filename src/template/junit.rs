@@ -0,0 +1,179 @@
+/// Module that writes the outcome of a run as a JUnit XML report, which most CI systems can display and gate on.
+///
+/// Every part of a day is a test case, classified by its puzzle, e.g. `2025-01`.
+/// Parts that panicked, timed out or did not return an answer fail, parts of unsolved days are skipped.
+use std::{fs, io, path::Path};
+
+use crate::template::{
+    Puzzle,
+    run_multi::RunReport,
+    runner::{PartResult, PartStatus},
+};
+
+/// Writes the JUnit XML report of a run to a file.
+pub fn write(report: &RunReport, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, render(report))
+}
+
+/// A test case of the report, along with its failure message or skip reason.
+enum Case {
+    Passed,
+    Failed(&'static str),
+    Skipped(&'static str),
+}
+
+fn render(report: &RunReport) -> String {
+    let mut cases: Vec<(Puzzle, String, f64, Case)> = vec![];
+
+    for puzzle in &report.puzzles {
+        match report.result(*puzzle) {
+            Some(result) => {
+                let mut parts: Vec<&PartResult> = result.parts.iter().collect();
+                parts.sort_unstable_by_key(|part| part.part);
+
+                for part in parts {
+                    let case = match part.status {
+                        PartStatus::Solved => Case::Passed,
+                        PartStatus::Unsolved => Case::Failed("did not return an answer"),
+                        PartStatus::Panicked => Case::Failed("panicked"),
+                        PartStatus::TimedOut => Case::Failed("timed out"),
+                    };
+                    let name = format!("part {}", part.part);
+                    cases.push((*puzzle, name, part.duration.as_secs_f64(), case));
+                }
+            }
+            None if report.failed.contains(puzzle) => {
+                cases.push((*puzzle, "run".into(), 0.0, Case::Failed("could not be run")));
+            }
+            None => {
+                for part in 1..=2 {
+                    let name = format!("part {part}");
+                    cases.push((*puzzle, name, 0.0, Case::Skipped("not solved yet")));
+                }
+            }
+        }
+    }
+
+    let failures = cases
+        .iter()
+        .filter(|(.., case)| matches!(case, Case::Failed(_)))
+        .count();
+    let skipped = cases
+        .iter()
+        .filter(|(.., case)| matches!(case, Case::Skipped(_)))
+        .count();
+    let time: f64 = cases.iter().map(|(_, _, time, _)| time).sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuite name=\"advent_of_code\" tests=\"{}\" failures=\"{failures}\" errors=\"0\" skipped=\"{skipped}\" time=\"{time:.6}\">\n",
+        cases.len()
+    ));
+
+    for (puzzle, name, time, case) in &cases {
+        let open = format!(
+            "  <testcase classname=\"{}\" name=\"{}\" time=\"{time:.6}\"",
+            escape(&puzzle.to_string()),
+            escape(name)
+        );
+        match case {
+            Case::Passed => xml.push_str(&format!("{open}/>\n")),
+            Case::Failed(message) => xml.push_str(&format!(
+                "{open}>\n    <failure message=\"{}\"/>\n  </testcase>\n",
+                escape(message)
+            )),
+            Case::Skipped(message) => xml.push_str(&format!(
+                "{open}>\n    <skipped message=\"{}\"/>\n  </testcase>\n",
+                escape(message)
+            )),
+        }
+    }
+
+    xml.push_str("</testsuite>\n");
+    xml
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{escape, render};
+    use crate::{
+        puzzle,
+        template::{
+            run_multi::RunReport,
+            runner::{PartResult, PartStatus, RunResult},
+        },
+    };
+
+    fn part(part: u8, status: PartStatus) -> PartResult {
+        PartResult {
+            puzzle: puzzle!(2025, 1),
+            part,
+            answer: (status == PartStatus::Solved).then(|| "42".into()),
+            status,
+            duration: Duration::from_micros(1_500),
+            stats: None,
+        }
+    }
+
+    #[test]
+    fn renders_reports() {
+        let report = RunReport {
+            puzzles: vec![puzzle!(2025, 1), puzzle!(2025, 2), puzzle!(2025, 3)],
+            results: vec![(
+                puzzle!(2025, 1),
+                RunResult {
+                    parse: None,
+                    parts: vec![part(2, PartStatus::Panicked), part(1, PartStatus::Solved)],
+                },
+            )],
+            failed: vec![puzzle!(2025, 2)],
+            timings: None,
+        };
+
+        assert_eq!(
+            render(&report),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuite name="advent_of_code" tests="5" failures="2" errors="0" skipped="2" time="0.003000">
+  <testcase classname="2025-01" name="part 1" time="0.001500"/>
+  <testcase classname="2025-01" name="part 2" time="0.001500">
+    <failure message="panicked"/>
+  </testcase>
+  <testcase classname="2025-02" name="run" time="0.000000">
+    <failure message="could not be run"/>
+  </testcase>
+  <testcase classname="2025-03" name="part 1" time="0.000000">
+    <skipped message="not solved yet"/>
+  </testcase>
+  <testcase classname="2025-03" name="part 2" time="0.000000">
+    <skipped message="not solved yet"/>
+  </testcase>
+</testsuite>
+"#
+        );
+    }
+
+    #[test]
+    fn escapes_attributes() {
+        assert_eq!(escape("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,186 @@
+/// Module that reads and writes example manifests, i.e. `data/examples/<year>/<day>.json`.
+///
+/// A manifest lists the example files of a day along with the expected answer of each part.
+/// The build script generates one test per example and part from it, see [`example_tests!`](crate::example_tests).
+///
+/// NOTE: the build script includes this file as well, so it may only depend on `std` and `tinyjson`.
+use std::{collections::HashMap, str::FromStr};
+
+use tinyjson::JsonValue;
+
+/// JSON numbers are parsed as `f64`, which can't represent every integer from 2^53 on.
+const MAX_EXACT_NUMBER: f64 = 9_007_199_254_740_992.0;
+
+/// An example file and the answers the parts are expected to return for it.
+///
+/// A part that is missing from the manifest is not tested against the example.
+/// A part that is listed as `null` is expected to return `None`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleCase {
+    /// File name relative to `data/examples/<year>`, e.g. `11-2.txt`.
+    pub file: String,
+    pub part_1: Option<Option<String>>,
+    pub part_2: Option<Option<String>>,
+}
+
+impl ExampleCase {
+    /// Returns the parts that are tested against this example, along with their expected answer.
+    pub fn parts(&self) -> impl Iterator<Item = (u8, Option<&str>)> {
+        [(1, &self.part_1), (2, &self.part_2)]
+            .into_iter()
+            .filter_map(|(part, expected)| Some((part, expected.as_ref()?.as_deref())))
+    }
+}
+
+/// The examples of a day, in the order they are listed in the manifest.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub examples: Vec<ExampleCase>,
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Manifest> for JsonValue {
+    fn from(value: &Manifest) -> Self {
+        JsonValue::Array(
+            value
+                .examples
+                .iter()
+                .map(|example| {
+                    let mut map: HashMap<String, JsonValue> = HashMap::new();
+                    map.insert("file".into(), JsonValue::String(example.file.clone()));
+
+                    for (key, expected) in
+                        [("part_1", &example.part_1), ("part_2", &example.part_2)]
+                    {
+                        if let Some(expected) = expected {
+                            map.insert(
+                                key.into(),
+                                match expected {
+                                    Some(answer) => JsonValue::String(answer.clone()),
+                                    None => JsonValue::Null,
+                                },
+                            );
+                        }
+                    }
+
+                    JsonValue::Object(map)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON file."))?;
+
+        let examples = json
+            .get::<Vec<JsonValue>>()
+            .ok_or("Expected manifest to be a JSON array.")?;
+
+        let examples = examples
+            .iter()
+            .map(|example| {
+                let json = example
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected manifest entries to be JSON objects.")?;
+
+                let file = json
+                    .get("file")
+                    .and_then(|v| v.get::<String>())
+                    .ok_or("Expected manifest entry.file to be a string.")?;
+
+                let expected = |key: &str| match json.get(key) {
+                    None => Ok(None),
+                    Some(value) => answer_from_json(value)
+                        .map(Some)
+                        .map_err(|e| format!("manifest entry.{key} {e}")),
+                };
+
+                Ok(ExampleCase {
+                    file: file.clone(),
+                    part_1: expected("part_1")?,
+                    part_2: expected("part_2")?,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Manifest { examples })
+    }
+}
+
+/// Reads an answer that is either `null`, a string or a number.
+/// NOTE: numbers are accepted as well, since answers are often filled in by hand.
+pub(crate) fn answer_from_json(value: &JsonValue) -> Result<Option<String>, &'static str> {
+    match value {
+        JsonValue::Null => Ok(None),
+        JsonValue::String(answer) => Ok(Some(answer.clone())),
+        JsonValue::Number(answer) if answer.abs() < MAX_EXACT_NUMBER => {
+            Ok(Some(answer.to_string()))
+        }
+        JsonValue::Number(_) => Err("is too large for a JSON number, put it in quotes instead."),
+        _ => Err("must be null, a string or a number."),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use super::{ExampleCase, Manifest};
+
+    #[test]
+    fn parses_manifests() {
+        let manifest = Manifest::from_str(
+            r#"[{ "file": "11-1.txt", "part_1": 5 }, { "file": "11-2.txt", "part_1": null, "part_2": "2" }]"#,
+        )
+        .unwrap();
+
+        let parts: Vec<_> = manifest
+            .examples
+            .iter()
+            .map(|e| e.parts().collect::<Vec<_>>())
+            .collect();
+        assert_eq!(
+            parts,
+            vec![vec![(1, Some("5"))], vec![(1, None), (2, Some("2"))]]
+        );
+    }
+
+    #[test]
+    fn rejects_inexact_numbers() {
+        let manifest = |answer: &str| {
+            Manifest::from_str(&format!(r#"[{{ "file": "01.txt", "part_1": {answer} }}]"#))
+        };
+
+        assert_eq!(
+            manifest("9007199254740991").unwrap().examples[0].part_1,
+            Some(Some("9007199254740991".into()))
+        );
+        assert!(manifest("9007199254740993").is_err());
+        assert!(manifest("1e21").is_err());
+        assert!(manifest(r#""9007199254740993""#).is_ok());
+    }
+
+    #[test]
+    fn round_trips_manifests() {
+        let manifest = Manifest {
+            examples: vec![ExampleCase {
+                file: "01.txt".into(),
+                part_1: Some(Some("3".into())),
+                part_2: Some(None),
+            }],
+        };
+        let json = JsonValue::from(&manifest).stringify().unwrap();
+        assert_eq!(Manifest::from_str(&json).unwrap(), manifest);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
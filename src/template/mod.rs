@@ -1,68 +1,200 @@
 use std::{env, fs};
 
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod registry;
 pub mod runner;
 
+pub use answers::{Answers, Verdict};
+pub use bench::BenchStats;
 pub use day::*;
+pub use examples::{Example, Examples};
+pub use manifest::{ExampleCase, Manifest};
+pub use puzzle::*;
+pub use registry::{Registry, Solution};
+pub use run_multi::{MultiOptions, Runner};
+pub use shape::InputShape;
+pub use submissions::{Outcome, Refusal, Submission, Submissions};
+pub use year::*;
 
+mod answers;
+mod bench;
+mod compare;
 mod day;
+mod examples;
+mod html;
+mod junit;
+mod manifest;
+mod output;
+mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
+mod shape;
+mod submissions;
+mod submit;
+mod templates;
 mod timings;
+mod watch;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a puzzle to a string. E.g. like `data/inputs/2025/01.txt`.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: Puzzle) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join("data")
+        .join(folder)
+        .join(puzzle.year().to_string())
+        .join(format!("{}.txt", puzzle.day()));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Helper function that reads a text file to string, appending a part suffix. E.g. like `2025/01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: Puzzle, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(puzzle.year().to_string())
+        .join(format!("{}-{part}.txt", puzzle.day()));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads an example file listed in the example manifest of a puzzle. E.g. like `2025/11-2.txt`.
+#[must_use]
+pub fn read_example(puzzle: Puzzle, file: &str) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd
+        .join("data")
+        .join("examples")
+        .join(puzzle.year().to_string())
+        .join(file);
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open example file")
+}
+
+/// Creates the constant `PUZZLE` and sets up the input and runner for each part.
+/// Also declares the static `SOLUTION`, which registers the parts for in-process execution
+/// by the `solutions` binary.
+///
+/// The puzzle belongs to the year configured via `AOC_YEAR` unless a year is passed explicitly,
+/// e.g. `solution!(1, year = 2024)`.
+///
+/// The optional part parameter (1 or 2) allows you to only run a single part of the solution.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The optional `parse` parameter declares a parse stage, e.g. `solution!(1, year = 2024, parse = parse)`.
+/// The input is then parsed once, and both parts receive a reference to the parsed value.
+/// The runner times the parse stage separately from the parts.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!($day, year = $crate::template::Year::configured().into_inner());
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!($day, 1, year = $crate::template::Year::configured().into_inner());
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!($day, 2, year = $crate::template::Year::configured().into_inner());
+    };
+    ($day:expr, parse = $parse:expr) => {
+        $crate::solution!($day, year = $crate::template::Year::configured().into_inner(), parse = $parse);
+    };
+    ($day:expr, 1, parse = $parse:expr) => {
+        $crate::solution!($day, 1, year = $crate::template::Year::configured().into_inner(), parse = $parse);
+    };
+    ($day:expr, 2, parse = $parse:expr) => {
+        $crate::solution!($day, 2, year = $crate::template::Year::configured().into_inner(), parse = $parse);
+    };
+    ($day:expr, year = $year:expr) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1] [part_two, 2]);
     };
+    ($day:expr, 1, year = $year:expr) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1]);
+    };
+    ($day:expr, 2, year = $year:expr) => {
+        $crate::solution!(@impl $year, $day, [part_two, 2]);
+    };
+    ($day:expr, year = $year:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl $year, $day, parse = $parse, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, 1, year = $year:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl $year, $day, parse = $parse, [part_one, 1]);
+    };
+    ($day:expr, 2, year = $year:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl $year, $day, parse = $parse, [part_two, 2]);
+    };
+
+    (@impl $year:expr, $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@define $year, $day, |input, options| {
+            use $crate::template::runner::*;
+            RunResult {
+                parse: None,
+                parts: vec![$( run_part($func, input, PUZZLE, $part, options) ),*],
+            }
+        }, |input, part| {
+            $( if part == $part {
+                return $func(input).map(|answer| answer.to_string());
+            } )*
+            None
+        });
+    };
+
+    (@impl $year:expr, $day:expr, parse = $parse:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@define $year, $day, |input, options| {
+            use $crate::template::runner::*;
+            let (parsed, parse) = run_parse($parse, input, PUZZLE, options);
+            RunResult {
+                parse: Some(parse),
+                // NOTE: the closure lets `&parsed` coerce to the parameter type of the part, e.g. `&Vec<T>` to `&[T]`.
+                parts: vec![$( run_part(|parsed| $func(parsed), &parsed, PUZZLE, $part, options) ),*],
+            }
+        }, |input, part| {
+            let parsed = $parse(input);
+            $( if part == $part {
+                return (|parsed| $func(parsed))(&parsed).map(|answer| answer.to_string());
+            } )*
+            None
+        });
+    };
+
+    (@define $year:expr, $day:expr, |$input:ident, $options:ident| $run:block, |$solve_input:ident, $part:ident| $solve:block) => {
+        /// The current puzzle.
+        const PUZZLE: $crate::template::Puzzle = $crate::puzzle!($year, $day);
+
+        /// The solution of the current puzzle, see [`Registry`]($crate::template::Registry).
+        pub static SOLUTION: &dyn $crate::template::Solution = {
+            struct Registered;
+
+            impl $crate::template::Solution for Registered {
+                fn puzzle(&self) -> $crate::template::Puzzle {
+                    PUZZLE
+                }
+
+                fn run(
+                    &self,
+                    $input: &str,
+                    $options: &$crate::template::runner::RunOptions,
+                ) -> $crate::template::runner::RunResult $run
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+                fn solve(&self, $solve_input: &str, $part: u8) -> Option<String> $solve
+            }
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+            &Registered
+        };
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let options = RunOptions::from_args();
+            let input = read_input(PUZZLE, &options);
+            SOLUTION.run(&input, &options);
         }
     };
 }
@@ -0,0 +1,93 @@
+/// Module that routes the output of the runner, so that days running in parallel don't interleave their output.
+///
+/// By default, output is printed immediately. While a thread captures its output (see [`capture`]),
+/// it is collected in a buffer instead, which the caller prints once the day finished.
+/// NOTE: only output of the runner is captured, `println!` calls in solutions still print immediately.
+use std::{
+    cell::RefCell,
+    fmt,
+    io::{self, Write},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// Output captured while running a day.
+pub type Buffer = Arc<Mutex<String>>;
+
+thread_local! {
+    static BUFFER: RefCell<Option<Buffer>> = const { RefCell::new(None) };
+}
+
+/// Captures the output of the current thread in `buffer`, or prints it immediately again if `None`.
+pub fn capture(buffer: Option<Buffer>) {
+    BUFFER.with(|current| *current.borrow_mut() = buffer);
+}
+
+/// Writes to the buffer of the current thread, or to stdout if it does not capture its output.
+/// Use [`out!`] and [`outln!`] instead of calling this directly.
+pub fn write(args: fmt::Arguments) {
+    BUFFER.with(|current| match current.borrow().as_ref() {
+        Some(buffer) => {
+            let mut buffer = buffer.lock().unwrap_or_else(PoisonError::into_inner);
+            let _ = fmt::Write::write_fmt(&mut *buffer, args);
+        }
+        None => {
+            // NOTE: flush, since intermediate results are printed without a newline.
+            let mut stdout = io::stdout().lock();
+            let _ = stdout.write_fmt(args);
+            let _ = stdout.flush();
+        }
+    });
+}
+
+/// Like `print!`, but captured while running days in parallel.
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::template::output::write(format_args!($($arg)*))
+    };
+}
+
+/// Like `println!`, but captured while running days in parallel.
+macro_rules! outln {
+    () => {
+        $crate::template::output::write(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::template::output::write(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+pub(crate) use {out, outln};
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use super::capture;
+
+    #[test]
+    fn captures_output_of_spawned_threads() {
+        let buffer = Arc::new(Mutex::new(String::new()));
+        capture(Some(buffer.clone()));
+
+        out!("Part 1: ");
+        outln!("{}", 42);
+
+        let inherited = buffer.clone();
+        thread::spawn(move || {
+            capture(Some(inherited));
+            outln!("Part 2: ✖");
+        })
+        .join()
+        .unwrap();
+
+        capture(None);
+        assert_eq!(*buffer.lock().unwrap(), "Part 1: 42\nPart 2: ✖\n");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,95 @@
+use std::fmt::Display;
+
+use crate::template::{Day, Year};
+
+/// Identifies a single puzzle by its event year and day.
+/// The day is guaranteed to be part of the year's event.
+///
+/// # Display
+/// This value displays as `<year>-<day>`, which is also the name of the solution binary.
+///
+/// ```
+/// # use advent_of_code::{day, year, template::Puzzle};
+/// let puzzle = Puzzle::new(year!(2024), day!(8)).unwrap();
+/// assert_eq!(puzzle.to_string(), "2024-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    year: Year,
+    day: Day,
+}
+
+impl Puzzle {
+    /// Creates a [`Puzzle`] if the day is part of the event year, returns [`None`] otherwise.
+    pub const fn new(year: Year, day: Day) -> Option<Self> {
+        if day.into_inner() > year.days() {
+            return None;
+        }
+        Some(Self { year, day })
+    }
+
+    pub fn year(self) -> Year {
+        self.year
+    }
+
+    pub fn day(self) -> Day {
+        self.day
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/// Returns an iterator over every puzzle of an event year.
+pub fn all_puzzles(year: Year) -> impl Iterator<Item = Puzzle> {
+    // NOTE: `all_days` only yields days that are part of the event.
+    year.all_days().map(move |day| Puzzle { year, day })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Puzzle`] value in a const context.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:expr, $day:expr) => {
+        const {
+            $crate::template::Puzzle::new($crate::year!($year), $crate::day!($day))
+                .expect("invalid puzzle, the day is not part of the event year")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Puzzle, all_puzzles};
+    use crate::{day, year};
+
+    #[test]
+    fn validates_day_against_year() {
+        assert!(Puzzle::new(year!(2024), day!(25)).is_some());
+        assert!(Puzzle::new(year!(2025), day!(12)).is_some());
+        assert!(Puzzle::new(year!(2025), day!(13)).is_none());
+    }
+
+    #[test]
+    fn displays_as_bin_name() {
+        assert_eq!(crate::puzzle!(2025, 3).to_string(), "2025-03");
+    }
+
+    #[test]
+    fn iterates_all_puzzles_of_a_year() {
+        assert_eq!(all_puzzles(year!(2024)).count(), 25);
+        assert_eq!(all_puzzles(year!(2025)).count(), 12);
+        assert_eq!(
+            all_puzzles(year!(2025)).last(),
+            Some(crate::puzzle!(2025, 12))
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -2,8 +2,8 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::Puzzle;
+use crate::template::timings::{PartTiming, Timings};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -26,8 +26,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: Puzzle) -> String {
+    format!("./src/bin/{puzzle}.rs")
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -59,18 +59,20 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Year | Day | Parse | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---: | :---:  |".into(),
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(timing.puzzle);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            "| {} | [Day {}]({}) | {} | {} | {} |",
+            timing.puzzle.year(),
+            timing.puzzle.day().into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_part(timing.parse.as_ref()),
+            format_part(timing.part_1.as_ref()),
+            format_part(timing.part_2.as_ref())
         ));
     }
 
@@ -81,6 +83,20 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
+fn format_part(part: Option<&PartTiming>) -> String {
+    match part {
+        Some(PartTiming {
+            duration,
+            stats: Some(stats),
+        }) => format!(
+            "`{duration}` ± {:.1?} (median `{:.1?}`, min `{:.1?}`, p95 `{:.1?}`)",
+            stats.stddev, stats.median, stats.min, stats.p95
+        ),
+        Some(PartTiming { duration, .. }) => format!("`{duration}`"),
+        None => "`-`".into(),
+    }
+}
+
 fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
     let positions = locate_table(s)?;
     let table = construct_table("##", timings, total_millis);
@@ -100,30 +116,34 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{puzzle, template::timings::Timing, template::timings::Timings};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
+                    puzzle: puzzle!(2025, 2),
+                    parse: Some("5ms".into()),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
+                    puzzle: puzzle!(2025, 4),
+                    parse: None,
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
                 },
             ],
+            history: vec![],
         }
     }
 
@@ -167,11 +187,11 @@ mod tests {
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Year | Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---: | :---:  |",
+            "| 2025 | [Day 1](./src/bin/2025-01.rs) | `-` | `10ms` | `20ms` |",
+            "| 2025 | [Day 2](./src/bin/2025-02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| 2025 | [Day 4](./src/bin/2025-04.rs) | `-` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -0,0 +1,221 @@
+/// Registry of solutions that can be executed in-process by the `solutions` binary.
+///
+/// Every day binary declares a `SOLUTION` via the [`solution!`](crate::solution) macro.
+/// The `solutions` binary (`src/solutions.rs`) includes all day modules with
+/// [`register_solutions!`](crate::register_solutions), so `all` and `time` can run every day
+/// without spawning one cargo invocation per day.
+use std::{
+    fmt::Display,
+    fs, io,
+    process::{Command, ExitStatus, Stdio},
+};
+
+use crate::template::{
+    Puzzle,
+    runner::{RunOptions, RunResult},
+};
+
+/// Path of the `solutions` binary source, which lists all registered day modules.
+pub const SOLUTIONS_PATH: &str = "src/solutions.rs";
+
+/// Marker for the start of the list of registered day modules.
+const REGISTER_START: &str = "advent_of_code::register_solutions! {";
+
+/// A solution that can be run in-process.
+/// Implemented for every day binary by the [`solution!`](crate::solution) macro.
+pub trait Solution: Sync {
+    /// The puzzle this solution solves.
+    fn puzzle(&self) -> Puzzle;
+
+    /// Runs the parse stage (if declared) and every implemented part against the input, printing the results.
+    fn run(&self, input: &str, options: &RunOptions) -> RunResult;
+
+    /// Solves a single part against the input without printing or timing it, e.g. in example tests.
+    /// Returns `None` if the part is not implemented or did not produce an answer.
+    fn solve(&self, input: &str, part: u8) -> Option<String>;
+}
+
+/// A set of solutions, sorted by puzzle.
+pub struct Registry {
+    solutions: Vec<&'static dyn Solution>,
+}
+
+impl Registry {
+    pub fn new(mut solutions: Vec<&'static dyn Solution>) -> Self {
+        solutions.sort_unstable_by_key(|solution| solution.puzzle());
+        Self { solutions }
+    }
+
+    /// Returns the solution for a puzzle, if one is registered.
+    pub fn get(&self, puzzle: Puzzle) -> Option<&'static dyn Solution> {
+        self.solutions
+            .iter()
+            .find(|solution| solution.puzzle() == puzzle)
+            .copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'static dyn Solution> + '_ {
+        self.solutions.iter().copied()
+    }
+}
+
+/// Declares the day modules of the `solutions` binary and a `registry()` function returning them.
+///
+/// ```ignore
+/// advent_of_code::register_solutions! {
+///     solution_2025_01 => "bin/2025-01.rs",
+/// }
+/// ```
+#[macro_export]
+macro_rules! register_solutions {
+    ($( $module:ident => $path:literal ),* $(,)?) => {
+        $(
+            #[path = $path]
+            #[allow(dead_code)]
+            mod $module;
+        )*
+
+        /// Returns every registered solution.
+        fn registry() -> $crate::template::Registry {
+            $crate::template::Registry::new(vec![$( $module::SOLUTION ),*])
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug)]
+pub enum Error {
+    BuildFailed,
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BuildFailed => write!(f, "the solutions binary could not be built."),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Builds the `solutions` binary and runs it with the given arguments, forwarding its output.
+/// Returns the exit status of the run.
+pub fn run_solutions_binary(args: &[String], is_release: bool) -> Result<ExitStatus, Error> {
+    let mut cargo_args = vec!["--quiet", "--bin", "solutions"];
+    if is_release {
+        cargo_args.push("--release");
+    }
+
+    // build first so that a compile error can be told apart from a failing run.
+    let build = Command::new("cargo")
+        .arg("build")
+        .args(&cargo_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()?;
+
+    if !build.success() {
+        return Err(Error::BuildFailed);
+    }
+
+    let status = Command::new("cargo")
+        .arg("run")
+        .args(&cargo_args)
+        .arg("--")
+        .args(args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()?;
+
+    Ok(status)
+}
+
+/// Adds a day module to the list of registered solutions in `src/solutions.rs`.
+/// Returns `false` if the module was already registered.
+pub fn register(puzzle: Puzzle) -> Result<bool, io::Error> {
+    let source = fs::read_to_string(SOLUTIONS_PATH)?;
+    match add_entry(&source, puzzle) {
+        Some(updated) => {
+            fs::write(SOLUTIONS_PATH, updated)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+fn add_entry(source: &str, puzzle: Puzzle) -> Option<String> {
+    let entry = format!(
+        "    solution_{}_{} => \"bin/{puzzle}.rs\",",
+        puzzle.year(),
+        puzzle.day()
+    );
+
+    let lines: Vec<&str> = source.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == REGISTER_START)?
+        + 1;
+    let end = start + lines[start..].iter().position(|line| line.trim() == "}")?;
+
+    let mut entries: Vec<String> = lines[start..end]
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| (*line).to_string())
+        .collect();
+
+    if entries.contains(&entry) {
+        return None;
+    }
+
+    entries.push(entry);
+    entries.sort();
+
+    let mut updated: Vec<String> = lines[..start].iter().map(|l| (*l).to_string()).collect();
+    updated.extend(entries);
+    updated.extend(lines[end..].iter().map(|l| (*l).to_string()));
+
+    Some(updated.join("\n") + "\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::add_entry;
+    use crate::puzzle;
+
+    const SOURCE: &str = "advent_of_code::register_solutions! {\n    solution_2025_03 => \"bin/2025-03.rs\",\n}\n\nfn main() {}\n";
+
+    #[test]
+    fn adds_entries_in_order() {
+        let updated = add_entry(SOURCE, puzzle!(2025, 1)).unwrap();
+        assert_eq!(
+            updated,
+            "advent_of_code::register_solutions! {\n    solution_2025_01 => \"bin/2025-01.rs\",\n    solution_2025_03 => \"bin/2025-03.rs\",\n}\n\nfn main() {}\n"
+        );
+    }
+
+    #[test]
+    fn skips_registered_entries() {
+        assert!(add_entry(SOURCE, puzzle!(2025, 3)).is_none());
+    }
+
+    #[test]
+    fn adds_entries_to_empty_list() {
+        let source = "advent_of_code::register_solutions! {\n}\n";
+        let updated = add_entry(source, puzzle!(2024, 20)).unwrap();
+        assert_eq!(
+            updated,
+            "advent_of_code::register_solutions! {\n    solution_2024_20 => \"bin/2024-20.rs\",\n}\n"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
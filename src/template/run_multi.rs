@@ -1,48 +1,609 @@
-use std::{collections::HashSet, io};
-
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    fs, io, mem,
+    path::PathBuf,
+    process::ExitStatus,
+    sync::{
+        PoisonError,
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::Duration,
+};
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Puzzle, Registry, aoc_cli, junit,
+    output::{self, Buffer, out, outln},
+    runner::{self, InputSource, PartResult, PartStatus, RunOptions, RunResult},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+use super::timings::{PartTiming, Timing, Timings};
 
-    let mut need_space = false;
+/// Stack size of the threads that run solutions in-process.
+/// Matches the main thread, since deeply recursive solutions overflow the default of 2 MiB.
+const SOLUTION_STACK_SIZE: usize = 8 * 1024 * 1024;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+/// Determines how [`run_multi`] executes solutions.
+pub enum Runner<'a> {
+    /// Run registered solutions in the current process.
+    /// Days with a timeout run in a child process of the current executable instead, so that they can be killed.
+    /// The executable needs to handle `solve <day> --year <year> --json [--time]`, see `src/solutions.rs`.
+    InProcess(&'a Registry),
+    /// Run every solution in its own binary via `cargo run`.
+    ChildProcess { is_release: bool },
+}
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+/// Options that control how [`run_multi`] runs a set of puzzles.
+#[derive(Clone, Debug)]
+pub struct MultiOptions {
+    /// Bench each part instead of executing it once.
+    pub timed: bool,
+    /// Abort days that take longer, their unfinished parts are reported as timed out.
+    pub timeout: Option<Duration>,
+    /// Number of days to run at the same time.
+    pub jobs: usize,
+    /// Write a JUnit XML report of the run to this path.
+    pub junit: Option<PathBuf>,
+}
+
+impl Default for MultiOptions {
+    fn default() -> Self {
+        Self {
+            timed: false,
+            timeout: None,
+            jobs: 1,
+            junit: None,
+        }
+    }
+}
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+impl MultiOptions {
+    /// Parses `--timeout <seconds>`, `--jobs <n>` and `--junit <path>`.
+    /// Whether the run is timed depends on the command, so it is left unset.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+            jobs: args.opt_value_from_fn("--jobs", parse_jobs)?.unwrap_or(1),
+            junit: args
+                .opt_value_from_os_str("--junit", |path| Ok::<_, String>(PathBuf::from(path)))?,
+            ..Self::default()
+        })
+    }
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    /// The arguments that select these options when passed to the `solutions` binary.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        if let Some(timeout) = self.timeout {
+            args.push("--timeout".into());
+            args.push(timeout.as_secs_f64().to_string());
+        }
+        if self.jobs > 1 {
+            args.push("--jobs".into());
+            args.push(self.jobs.to_string());
+        }
+        if let Some(path) = &self.junit {
+            args.push("--junit".into());
+            args.push(path.to_string_lossy().into_owned());
+        }
+        args
+    }
+}
+
+/// Parses the value of `--timeout`, i.e. a number of seconds like `10` or `0.5`.
+fn parse_timeout(secs: &str) -> Result<Duration, String> {
+    secs.parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(|| format!("expected a positive number of seconds, got \"{secs}\""))
+}
+
+/// Parses the value of `--jobs`, i.e. the number of days to run at the same time.
+fn parse_jobs(jobs: &str) -> Result<usize, String> {
+    jobs.parse::<usize>()
+        .ok()
+        .filter(|jobs| *jobs > 0)
+        .ok_or_else(|| format!("expected a positive number of jobs, got \"{jobs}\""))
+}
+
+/// The outcome of running a set of puzzles.
+pub struct RunReport {
+    /// Every puzzle of the run, sorted.
+    pub puzzles: Vec<Puzzle>,
+    /// Results of every puzzle that could be run, sorted by puzzle.
+    pub results: Vec<(Puzzle, RunResult)>,
+    /// Puzzles that have a solution, but could not be run, e.g. because their binary does not compile.
+    pub failed: Vec<Puzzle>,
+    /// Timings of these puzzles, only present if the run was timed.
+    pub timings: Option<Timings>,
+}
+
+/// How a day fared in a run, see [`RunReport::verdicts`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayVerdict {
+    /// Every part returned an answer.
+    Solved,
+    /// The day could not be run, or a part panicked, timed out or did not return an answer.
+    Failed,
+    /// The day has no solution or no input yet.
+    Unsolved,
+}
+
+impl RunReport {
+    /// Returns the result of a puzzle, if it could be run.
+    pub fn result(&self, puzzle: Puzzle) -> Option<&RunResult> {
+        self.results
+            .iter()
+            .find(|(p, _)| *p == puzzle)
+            .map(|(_, result)| result)
+    }
+
+    /// Returns the verdict of every puzzle, sorted by puzzle.
+    pub fn verdicts(&self) -> Vec<(Puzzle, DayVerdict)> {
+        self.puzzles
+            .iter()
+            .map(|puzzle| {
+                let verdict = match self.result(*puzzle) {
+                    Some(result)
+                        if !result.parts.is_empty()
+                            && result.parts.iter().all(|p| p.status == PartStatus::Solved) =>
+                    {
+                        DayVerdict::Solved
+                    }
+                    Some(_) => DayVerdict::Failed,
+                    None if self.failed.contains(puzzle) => DayVerdict::Failed,
+                    None => DayVerdict::Unsolved,
+                };
+                (*puzzle, verdict)
+            })
+            .collect()
+    }
+
+    /// Whether no day failed.
+    pub fn is_success(&self) -> bool {
+        self.verdicts()
+            .iter()
+            .all(|(_, verdict)| *verdict != DayVerdict::Failed)
+    }
+
+    /// Prints a single line that counts the solved, failed and unsolved days, listing the failed ones.
+    pub fn print_summary(&self) {
+        let verdicts = self.verdicts();
+        let with = |verdict: DayVerdict| -> Vec<String> {
+            verdicts
+                .iter()
+                .filter(|(_, v)| *v == verdict)
+                .map(|(puzzle, _)| puzzle.to_string())
+                .collect()
+        };
+        let failed = with(DayVerdict::Failed);
+
+        let failed = if failed.is_empty() {
+            "0 failed".to_string()
+        } else {
+            format!("{} failed ({})", failed.len(), failed.join(", "))
+        };
+
+        println!(
+            "{ANSI_BOLD}Days: {} solved, {failed}, {} unsolved.{ANSI_RESET}",
+            with(DayVerdict::Solved).len(),
+            with(DayVerdict::Unsolved).len()
+        );
+    }
+}
+
+/// The result of running a single day.
+enum DayResult {
+    Ran(RunResult),
+    NotSolved,
+    Failed,
+}
+
+/// Runs a set of puzzles and prints their output in order.
+/// With more than one job, days run at the same time and the output of each day is printed once it finished.
+pub fn run_multi(
+    puzzles_to_run: &HashSet<Puzzle>,
+    runner: &Runner,
+    options: &MultiOptions,
+) -> RunReport {
+    // NOTE: use non-duplicate, sorted puzzle values.
+    let mut puzzles: Vec<Puzzle> = puzzles_to_run.iter().copied().collect();
+    puzzles.sort_unstable();
+
+    let day_results = if options.jobs > 1 {
+        run_parallel(&puzzles, runner, options)
+    } else {
+        puzzles
+            .iter()
+            .enumerate()
+            .map(|(index, puzzle)| {
+                if index > 0 {
+                    println!();
+                }
+                run_day(runner, *puzzle, options)
+            })
+            .collect()
+    };
+
+    let mut results: Vec<(Puzzle, RunResult)> = Vec::with_capacity(puzzles.len());
+    let mut failed: Vec<Puzzle> = vec![];
 
-    if is_timed {
-        let timings = Timings { data: timings };
+    for (puzzle, result) in puzzles.iter().zip(day_results) {
+        match result {
+            DayResult::Ran(result) => results.push((*puzzle, result)),
+            DayResult::Failed => failed.push(*puzzle),
+            DayResult::NotSolved => {}
+        }
+    }
+
+    let timings = options.timed.then(|| {
+        let timings = Timings {
+            data: results
+                .iter()
+                .map(|(puzzle, result)| timing_from_result(*puzzle, result))
+                .collect(),
+            history: vec![],
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+        timings
+    });
+
+    let report = RunReport {
+        puzzles,
+        results,
+        failed,
+        timings,
+    };
+
+    if let Some(path) = &options.junit {
+        match junit::write(&report, path) {
+            Ok(()) => println!("\nWrote JUnit report to \"{}\".", path.display()),
+            Err(e) => eprintln!(
+                "Failed to write JUnit report to \"{}\": {e}",
+                path.display()
+            ),
+        }
+    }
+
+    report
+}
+
+/// Runs days on `options.jobs` threads, capturing the output of every day.
+/// The output is printed in the order of the puzzles as soon as all preceding days finished.
+fn run_parallel(puzzles: &[Puzzle], runner: &Runner, options: &MultiOptions) -> Vec<DayResult> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(puzzles.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || {
+                while let Some(&puzzle) = puzzles.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let buffer = Buffer::default();
+                    output::capture(Some(buffer.clone()));
+                    let result = run_day(runner, puzzle, options);
+                    output::capture(None);
+
+                    let output = mem::take(&mut *buffer.lock().unwrap());
+                    if sender.send((puzzle, output, result)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut results = Vec::with_capacity(puzzles.len());
+
+        for (puzzle, output, result) in receiver {
+            finished.insert(puzzle, (output, result));
+
+            while let Some((output, result)) = puzzles
+                .get(results.len())
+                .and_then(|puzzle| finished.remove(puzzle))
+            {
+                if !results.is_empty() {
+                    println!();
+                }
+                print!("{output}");
+                results.push(result);
+            }
+        }
+
+        results
+    })
+}
+
+/// Runs a single day with the given runner, printing its results.
+fn run_day(runner: &Runner, puzzle: Puzzle, options: &MultiOptions) -> DayResult {
+    outln!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day());
+    outln!("------");
+
+    let result = match runner {
+        Runner::InProcess(registry) => {
+            run_in_process(registry, puzzle, options.timed, options.timeout)
+        }
+        Runner::ChildProcess { is_release } => day_result(
+            puzzle,
+            child_commands::run_solution(
+                puzzle,
+                &InputSource::Puzzle,
+                options.timed,
+                *is_release,
+                options.timeout,
+            ),
+        ),
+    };
+
+    match result {
+        DayResult::NotSolved => outln!("Not solved."),
+        DayResult::Failed => outln!("Failed to run."),
+        DayResult::Ran(_) => {}
+    }
+
+    result
+}
+
+/// Maps the outcome of a solution binary to the result of its day.
+fn day_result(puzzle: Puzzle, result: Result<RunResult, Error>) -> DayResult {
+    match result {
+        Ok(result) if result.parse.is_some() || !result.parts.is_empty() => DayResult::Ran(result),
+        Ok(_) => DayResult::NotSolved,
+        Err(e) => {
+            eprintln!("Failed to run {puzzle}: {e}");
+            DayResult::Failed
+        }
+    }
+}
+
+/// Run a registered solution in the current process, on a thread of its own.
+/// The day is not solved if the puzzle has no registered solution or no input.
+///
+/// Panics of a part are caught by the runner (see [`run_part`](crate::template::runner::run_part)),
+/// a panic of the parse stage marks all parts as panicked.
+/// A thread can't be killed, so days with a timeout run in a child process of the current executable instead.
+fn run_in_process(
+    registry: &Registry,
+    puzzle: Puzzle,
+    is_timed: bool,
+    timeout: Option<Duration>,
+) -> DayResult {
+    let Some(solution) = registry.get(puzzle) else {
+        return DayResult::NotSolved;
+    };
+
+    let input_path = aoc_cli::get_input_path(puzzle);
+    let input = match fs::read_to_string(&input_path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Could not read input file \"{input_path}\": {e}");
+            return DayResult::NotSolved;
+        }
+    };
+
+    if let Some(timeout) = timeout {
+        match child_commands::current_exe_command(puzzle, is_timed) {
+            Ok(command) => {
+                return day_result(
+                    puzzle,
+                    child_commands::run_command(puzzle, command, Some(timeout)),
+                );
+            }
+            Err(e) => {
+                eprintln!("Could not start a process for {puzzle}, running it on a thread: {e}")
+            }
+        }
+    }
+
+    let options = RunOptions {
+        timed: is_timed,
+        submit: None,
+        json: false,
+    };
+
+    // the thread writes to a buffer of its own, so that a thread that timed out can't print into later days.
+    let (parts_sender, parts_receiver) = mpsc::channel();
+    let (sender, receiver) = mpsc::channel();
+    let buffer = Buffer::default();
+    let thread_buffer = buffer.clone();
+    let spawned = thread::Builder::new()
+        .name(puzzle.to_string())
+        .stack_size(SOLUTION_STACK_SIZE)
+        .spawn(move || {
+            output::capture(Some(thread_buffer));
+            runner::report_parts(parts_sender);
+            let _ = sender.send(solution.run(&input, &options));
+        });
+    if let Err(e) = spawned {
+        eprintln!("Could not start a thread for {puzzle}: {e}");
+        return DayResult::Failed;
+    }
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+
+    let status = match received {
+        Ok(result) => {
+            flush(&buffer);
+            return DayResult::Ran(result);
+        }
+        // a disconnected channel means that the thread panicked before sending its result.
+        Err(RecvTimeoutError::Disconnected) => {
+            flush(&buffer);
+            PartStatus::Panicked
+        }
+        // the thread keeps running, so its output is dropped and the parts it finished are printed instead.
+        Err(RecvTimeoutError::Timeout) => PartStatus::TimedOut,
+    };
+
+    let mut result = RunResult {
+        parse: None,
+        parts: parts_receiver.try_iter().collect(),
+    };
+    let printed = match status {
+        PartStatus::TimedOut => 0,
+        _ => result.parts.len(),
+    };
+    result.fill_missing_parts(puzzle, status);
+    result.parts[printed..].iter().for_each(PartResult::print);
+    DayResult::Ran(result)
+}
+
+/// Writes the output a solution thread captured to the output of the current thread.
+fn flush(buffer: &Buffer) {
+    let captured = mem::take(&mut *buffer.lock().unwrap_or_else(PoisonError::into_inner));
+    out!("{captured}");
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn timing_from_result(puzzle: Puzzle, result: &RunResult) -> Timing {
+    let mut timing = Timing {
+        puzzle,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+    };
+
+    if let Some(parse) = &result.parse {
+        timing.parse = Some(PartTiming {
+            duration: format!("{:.1?}", parse.duration),
+            stats: parse.stats,
+        });
+        timing.total_nanos += parse.duration.as_nanos() as f64;
+    }
+
+    for part in result.parts.iter().filter(|part| part.answer.is_some()) {
+        let part_timing = PartTiming {
+            duration: format!("{:.1?}", part.duration),
+            stats: part.stats,
+        };
+        match part.part {
+            1 => timing.part_1 = Some(part_timing),
+            2 => timing.part_2 = Some(part_timing),
+            _ => {}
+        }
+        timing.total_nanos += part.duration.as_nanos() as f64;
+    }
+
+    timing
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DayVerdict, RunReport, timing_from_result};
+    use crate::{
+        puzzle,
+        template::runner::{ParseResult, PartResult, PartStatus, RunResult},
+    };
+
+    #[test]
+    fn derives_day_verdicts() {
+        let result = |statuses: &[PartStatus]| RunResult {
+            parse: None,
+            parts: statuses
+                .iter()
+                .enumerate()
+                .map(|(i, status)| {
+                    PartResult::failed(puzzle!(2025, 1), i as u8 + 1, *status, Duration::ZERO)
+                })
+                .collect(),
+        };
+
+        let report = RunReport {
+            puzzles: vec![
+                puzzle!(2025, 1),
+                puzzle!(2025, 2),
+                puzzle!(2025, 3),
+                puzzle!(2025, 4),
+            ],
+            results: vec![
+                (
+                    puzzle!(2025, 1),
+                    result(&[PartStatus::Solved, PartStatus::Solved]),
+                ),
+                (
+                    puzzle!(2025, 2),
+                    result(&[PartStatus::Solved, PartStatus::Unsolved]),
+                ),
+            ],
+            failed: vec![puzzle!(2025, 3)],
+            timings: None,
+        };
+
+        let verdicts: Vec<DayVerdict> = report.verdicts().into_iter().map(|(_, v)| v).collect();
+        assert_eq!(
+            verdicts,
+            vec![
+                DayVerdict::Solved,
+                DayVerdict::Failed,
+                DayVerdict::Failed,
+                DayVerdict::Unsolved
+            ]
+        );
+        assert!(!report.is_success());
+    }
+
+    #[test]
+    fn builds_timing_from_results() {
+        let parts = vec![
+            PartResult {
+                puzzle: puzzle!(2025, 1),
+                part: 1,
+                answer: Some("42".into()),
+                status: PartStatus::Solved,
+                duration: Duration::from_nanos(1500),
+                stats: None,
+            },
+            PartResult {
+                puzzle: puzzle!(2025, 1),
+                part: 2,
+                answer: None,
+                status: PartStatus::Unsolved,
+                duration: Duration::from_nanos(500),
+                stats: None,
+            },
+        ];
+        let result = RunResult { parse: None, parts };
+        let timing = timing_from_result(puzzle!(2025, 1), &result);
+        assert_eq!(timing.parse, None);
+        assert_eq!(timing.part_1, Some("1.5µs".into()));
+        assert_eq!(timing.part_2, None);
+        assert_eq!(timing.total_nanos, 1500_f64);
+    }
+
+    #[test]
+    fn builds_timing_with_parse_stage() {
+        let result = RunResult {
+            parse: Some(ParseResult {
+                puzzle: puzzle!(2025, 1),
+                duration: Duration::from_nanos(500),
+                stats: None,
+            }),
+            parts: vec![PartResult {
+                puzzle: puzzle!(2025, 1),
+                part: 2,
+                answer: Some("42".into()),
+                status: PartStatus::Solved,
+                duration: Duration::from_nanos(1500),
+                stats: None,
+            }],
+        };
+        let timing = timing_from_result(puzzle!(2025, 1), &result);
+        assert_eq!(timing.parse, Some("500.0ns".into()));
+        assert_eq!(timing.part_2, Some("1.5µs".into()));
+        assert_eq!(timing.total_nanos, 2000_f64);
     }
 }
 
@@ -50,9 +611,21 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    /// The binary exited unsuccessfully without reporting any result, e.g. because it does not compile.
+    Failed(ExitStatus),
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not read the output of the binary."),
+            Error::Failed(status) => write!(f, "the binary exited with {status}."),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -60,47 +633,112 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: Puzzle) -> String {
+    format!("./src/bin/{puzzle}.rs")
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading their `--json` output.
 pub mod child_commands {
     use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use crate::template::{
+        Puzzle,
+        aoc_cli::get_input_path,
+        output::outln,
+        runner::{InputSource, ParseResult, PartResult, PartStatus, RunResult},
+    };
     use std::{
+        env,
         io::{BufRead, BufReader},
-        path::Path,
+        path::{Path, PathBuf},
         process::{Command, Stdio},
+        sync::mpsc::{self, RecvTimeoutError},
         thread,
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// Run the solution bin for a given day.
+    /// If `timeout` is set, the binary is killed once it runs longer, and its remaining parts are reported as timed out.
+    pub fn run_solution(
+        puzzle: Puzzle,
+        input: &InputSource,
+        is_timed: bool,
+        is_release: bool,
+        timeout: Option<Duration>,
+    ) -> Result<RunResult, Error> {
+        // skip command invocation for days that have not been scaffolded or downloaded yet.
+        // other inputs than the puzzle input are left to the binary, which reports them if they are missing.
+        let input_path = (*input == InputSource::Puzzle).then(|| get_input_path(puzzle));
+        if !is_runnable(
+            Path::new(&get_path_for_bin(puzzle)),
+            input_path.as_deref().map(Path::new),
+        ) {
+            return Ok(RunResult::default());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = puzzle.to_string();
 
+        // build the binary first, so that compiling it does not count against the timeout.
+        let mut build_args = vec!["build", "--quiet", "--bin", &bin_name];
         if is_release {
-            args.push("--release");
+            build_args.push("--release");
+        }
+        let status = Command::new("cargo").args(&build_args).status()?;
+        if !status.success() {
+            return Err(Error::Failed(status));
         }
 
+        // run the built binary directly, since killing `cargo run` would leave the binary running.
+        let mut command = Command::new(get_path_for_target_bin(puzzle, is_release));
+
+        // request one JSON record per part, mirror `--time` flag to child invocations.
+        command.arg("--json");
+
         if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            command.arg("--time");
         }
 
+        command.args(input.to_args());
+        run_command(puzzle, command, timeout)
+    }
+
+    /// Returns the path of the binary that `cargo build` produces for a day.
+    fn get_path_for_target_bin(puzzle: Puzzle, is_release: bool) -> PathBuf {
+        let target_dir = env::var_os("CARGO_TARGET_DIR").map_or("target".into(), PathBuf::from);
+        let profile = if is_release { "release" } else { "debug" };
+        target_dir
+            .join(profile)
+            .join(format!("{puzzle}{}", env::consts::EXE_SUFFIX))
+    }
+
+    /// Builds the command that runs a registered solution in a child process of the current executable,
+    /// see [`Runner::InProcess`](super::Runner::InProcess).
+    pub fn current_exe_command(puzzle: Puzzle, is_timed: bool) -> Result<Command, Error> {
+        let mut command = Command::new(env::current_exe()?);
+        command.args([
+            "solve".into(),
+            puzzle.day().to_string(),
+            "--year".into(),
+            puzzle.year().to_string(),
+            "--json".into(),
+        ]);
+        if is_timed {
+            command.arg("--time");
+        }
+        Ok(command)
+    }
+
+    /// Run a command that prints the JSON records of a solution, e.g. a solution bin.
+    /// If `timeout` is set, the command is killed once it runs longer, and its remaining parts are reported as timed out.
+    pub fn run_command(
+        puzzle: Puzzle,
+        mut command: Command,
+        timeout: Option<Duration>,
+    ) -> Result<RunResult, Error> {
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while collecting part records.
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
+        let mut cmd = command
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -108,150 +746,220 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let mut output = RunResult::default();
 
         let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+            stderr.lines().map_while(Result::ok).for_each(|line| {
+                eprintln!("{line}");
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
-
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
-    }
+        // stdout is read on a thread of its own, so that waiting for the next line can time out.
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for line in stdout.lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut timed_out = false;
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+        loop {
+            let received = match deadline {
+                Some(deadline) => {
+                    receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
                 }
+                None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            let line = match received {
+                Ok(line) => line,
+                Err(RecvTimeoutError::Disconnected) => break,
+                Err(RecvTimeoutError::Timeout) => {
+                    cmd.kill()?;
+                    timed_out = true;
+                    let missing = output.parts.len();
+                    output.fill_missing_parts(puzzle, PartStatus::TimedOut);
+                    output.parts[missing..].iter().for_each(PartResult::print);
+                    break;
+                }
+            };
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+            match parse_record(&line, puzzle) {
+                Some(Record::Parse(result)) => {
+                    result.print();
+                    output.parse = Some(result);
                 }
+                Some(Record::Part(result)) => {
+                    result.print();
+                    output.parts.push(result);
+                }
+                // output printed by the solution itself, e.g. debug output.
+                None => outln!("{line}"),
+            }
+        }
 
-                timings.total_nanos += nanos;
-            });
+        thread.join().unwrap();
+        let status = cmd.wait()?;
 
-        timings
+        // e.g. a compile error, a panic of the parse stage or a stack overflow.
+        if !status.success() && !timed_out {
+            if output.parse.is_none() && output.parts.is_empty() {
+                return Err(Error::Failed(status));
+            }
+            let finished = output.parts.len();
+            output.fill_missing_parts(puzzle, PartStatus::Panicked);
+            output.parts[finished..].iter().for_each(PartResult::print);
+        }
+
+        Ok(output)
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// A day can only be run once it has a binary and an input, otherwise it is not solved yet.
+    /// NOTE: without an input, the binary would panic, which would count as a failed day.
+    fn is_runnable(bin_path: &Path, input_path: Option<&Path>) -> bool {
+        bin_path.exists() && input_path.is_none_or(Path::exists)
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    /// A JSON record printed by a solution binary.
+    #[derive(Debug)]
+    enum Record {
+        Parse(ParseResult),
+        Part(PartResult),
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    /// Parses a record printed by a solution binary.
+    /// Records that belong to a different puzzle are ignored.
+    fn parse_record(line: &str, puzzle: Puzzle) -> Option<Record> {
+        if let Some(result) = PartResult::from_json_line(line) {
+            return (result.puzzle == puzzle).then_some(Record::Part(result));
+        }
+        ParseResult::from_json_line(line)
+            .filter(|result| result.puzzle == puzzle)
+            .map(Record::Parse)
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::{
+            env, fs,
+            process::{self, Command},
+            time::{Duration, Instant},
+        };
 
-        use crate::day;
+        use super::{Record, is_runnable, parse_record, run_command};
+        use crate::{
+            puzzle,
+            template::{
+                BenchStats,
+                runner::{ParseResult, PartResult, PartStatus},
+            },
+        };
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        #[cfg(unix)]
+        fn kills_commands_that_time_out() {
+            let mut command = Command::new("sleep");
+            command.arg("60");
+
+            let start = Instant::now();
+            let result =
+                run_command(puzzle!(2025, 1), command, Some(Duration::from_millis(500))).unwrap();
+
+            assert!(start.elapsed() < Duration::from_secs(10));
+            let statuses: Vec<_> = result.parts.iter().map(|part| part.status).collect();
+            assert_eq!(statuses, vec![PartStatus::TimedOut, PartStatus::TimedOut]);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        fn skips_days_without_input() {
+            let dir = env::temp_dir().join(format!("aoc-runnable-{}", process::id()));
+            fs::create_dir_all(&dir).unwrap();
+            let bin = dir.join("2025-01.rs");
+            let input = dir.join("01.txt");
+
+            assert!(!is_runnable(&bin, Some(&input)));
+            fs::write(&bin, "").unwrap();
+            // scaffolded, but the input was not downloaded, e.g. on CI.
+            assert!(!is_runnable(&bin, Some(&input)));
+            // other inputs are checked by the binary itself.
+            assert!(is_runnable(&bin, None));
+            fs::write(&input, "").unwrap();
+            assert!(is_runnable(&bin, Some(&input)));
+
+            fs::remove_dir_all(&dir).unwrap();
+        }
+
+        fn parse_part(line: &str) -> PartResult {
+            match parse_record(line, puzzle!(2025, 1)) {
+                Some(Record::Part(result)) => result,
+                record => panic!("expected a part record, got {record:?}"),
+            }
+        }
+
+        fn result(answer: Option<&str>) -> PartResult {
+            PartResult {
+                puzzle: puzzle!(2025, 1),
+                part: 1,
+                answer: answer.map(Into::into),
+                status: PartStatus::of(&answer),
+                duration: Duration::from_nanos(74_130_074),
+                stats: BenchStats::from_samples(&[Duration::from_nanos(74_130_074); 3], 1),
+            }
+        }
+
+        #[test]
+        fn round_trips_records() {
+            let line = result(Some("42")).to_json_line();
+            let parsed = parse_part(&line);
+            assert_eq!(parsed.part, 1);
+            assert_eq!(parsed.answer.as_deref(), Some("42"));
+            assert_eq!(parsed.duration, Duration::from_nanos(74_130_074));
+            assert_eq!(parsed.samples(), 3);
+            assert_eq!(parsed.stats, result(Some("42")).stats);
+        }
+
+        #[test]
+        fn parses_with_patterns_in_answer() {
+            let answer = "@ @ @ ( ) ms (2s @ 5 samples)\n\"quoted\"";
+            let line = result(Some(answer)).to_json_line();
+            let parsed = parse_part(&line);
+            assert_eq!(parsed.answer.as_deref(), Some(answer));
         }
 
         #[test]
         fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+            let line = result(None).to_json_line();
+            let parsed = parse_part(&line);
+            assert!(parsed.answer.is_none());
+        }
+
+        #[test]
+        fn parses_parse_stage() {
+            let result = ParseResult {
+                puzzle: puzzle!(2025, 1),
+                duration: Duration::from_nanos(1_500),
+                stats: BenchStats::from_samples(&[Duration::from_nanos(1_500); 3], 1),
+            };
+            match parse_record(&result.to_json_line(), puzzle!(2025, 1)) {
+                Some(Record::Parse(parsed)) => {
+                    assert_eq!(parsed.duration, result.duration);
+                    assert_eq!(parsed.stats, result.stats);
+                }
+                record => panic!("expected a parse record, got {record:?}"),
+            }
+        }
+
+        #[test]
+        fn ignores_other_output() {
+            assert!(
+                parse_record("Part 1: 0 (74.13ns @ 100000 samples)", puzzle!(2025, 1)).is_none()
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert!(parse_record("{\"debug\": true}", puzzle!(2025, 1)).is_none());
+            assert!(parse_record(&result(Some("1")).to_json_line(), puzzle!(2025, 2)).is_none());
         }
     }
 }
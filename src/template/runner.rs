@@ -1,35 +1,625 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt::Display;
-use std::hint::black_box;
-use std::io::{Write, stdout};
-use std::process::Output;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::mpsc::Sender;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, fs, process};
+
+use tinyjson::JsonValue;
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::bench::bench;
+use crate::template::output::{out, outln};
+use crate::template::submit;
+use crate::template::{ANSI_ITALIC, ANSI_RESET, BenchStats, Day, Puzzle, Year, read_file};
+
+/// Options that control how solution parts are run.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunOptions {
+    /// Bench each part instead of executing it once.
+    pub timed: bool,
+    /// Submit the result of this part.
+    pub submit: Option<u8>,
+    /// Print a JSON record per part instead of human-readable output.
+    pub json: bool,
+}
+
+impl RunOptions {
+    /// Parse the arguments passed to a solution binary, i.e. `--time`, `--json` and `--submit <part>`.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let submit = args.iter().position(|x| x == "--submit").map(|index| {
+            match args.get(index + 1).map(|part| part.parse::<u8>()) {
+                Some(Ok(part)) => part,
+                _ => {
+                    eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+                    process::exit(1);
+                }
+            }
+        });
+
+        Self {
+            timed: args.iter().any(|x| x == "--time"),
+            submit,
+            json: args.iter().any(|x| x == "--json"),
+        }
+    }
+}
+
+/// Where a solution binary reads its input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input, i.e. `data/inputs/<year>/<day>.txt`.
+    #[default]
+    Puzzle,
+    /// An example, i.e. `data/examples/<year>/<day>.txt` or `<day>-<k>.txt` if `k` is set.
+    Example(Option<u8>),
+    /// A file, e.g. a hand-crafted edge case.
+    File(String),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parse the arguments passed to a solution binary, i.e. `--example [k]` and `--input <path>`.
+    /// `--input -` reads the input from stdin.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        match InputSource::parse(&args) {
+            Ok(source) => source,
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    fn parse(args: &[String]) -> Result<Self, String> {
+        if let Some(index) = args.iter().position(|x| x == "--input") {
+            return match args.get(index + 1).map(String::as_str) {
+                Some("-") => Ok(InputSource::Stdin),
+                Some(path) if !path.starts_with("--") => Ok(InputSource::File(path.into())),
+                _ => Err(
+                    "Unexpected command-line input. Format: cargo solve 1 --input <path>".into(),
+                ),
+            };
+        }
+
+        Ok(match args.iter().position(|x| x == "--example") {
+            Some(index) => {
+                InputSource::Example(args.get(index + 1).and_then(|k| k.parse::<u8>().ok()))
+            }
+            None => InputSource::Puzzle,
+        })
+    }
+
+    /// The arguments that select this input source when passed to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(k)) => vec!["--example".into(), k.to_string()],
+            InputSource::File(path) => vec!["--input".into(), path.clone()],
+            InputSource::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    /// Reads the input of a puzzle from this source.
+    /// Without `k`, an example falls back to `<day>-1.txt` if the day has no `<day>.txt`.
+    pub fn read(&self, puzzle: Puzzle) -> Result<String, String> {
+        let read = |path: PathBuf| {
+            fs::read_to_string(&path)
+                .map_err(|e| format!("Could not read \"{}\": {e}", path.display()))
+        };
+
+        let examples = PathBuf::from("data")
+            .join("examples")
+            .join(puzzle.year().to_string());
+
+        match self {
+            InputSource::Puzzle => Ok(read_file("inputs", puzzle)),
+            InputSource::Example(Some(k)) => {
+                read(examples.join(format!("{}-{k}.txt", puzzle.day())))
+            }
+            InputSource::Example(None) => {
+                let path = examples.join(format!("{}.txt", puzzle.day()));
+                if path.exists() {
+                    read(path)
+                } else {
+                    read(examples.join(format!("{}-1.txt", puzzle.day())))
+                }
+            }
+            InputSource::File(path) => read(PathBuf::from(path)),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("Could not read stdin: {e}"))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Reads the input selected by the arguments passed to a solution binary, exits if it can't be read.
+/// Submitting is only allowed for the puzzle input.
+pub fn read_input(puzzle: Puzzle, options: &RunOptions) -> String {
+    let source = InputSource::from_args();
+
+    if source != InputSource::Puzzle && options.submit.is_some() {
+        eprintln!("Only answers for the puzzle input can be submitted.");
+        process::exit(1);
+    }
+
+    match source.read(puzzle) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Whether a solution part produced an answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    Unsolved,
+    /// The part panicked, its panic was caught so that the remaining parts still run.
+    Panicked,
+    /// The part did not finish within the timeout of its day, see `cargo all --timeout`.
+    TimedOut,
+}
+
+impl PartStatus {
+    /// Returns the status of a part that returned normally.
+    pub fn of<T>(answer: &Option<T>) -> Self {
+        if answer.is_some() {
+            PartStatus::Solved
+        } else {
+            PartStatus::Unsolved
+        }
+    }
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Panicked => "panicked",
+            PartStatus::TimedOut => "timed_out",
+        }
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            PartStatus::Solved => "ok",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Panicked => "panicked",
+            PartStatus::TimedOut => "timed out",
+        };
+        write!(f, "{label}")
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "panicked" => Ok(PartStatus::Panicked),
+            "timed_out" => Ok(PartStatus::TimedOut),
+            x => Err(format!("unknown part status `{x}`.")),
+        }
+    }
+}
+
+/// The outcome of running a single solution part.
+/// With `--json`, solution binaries print one of these per part as a JSON line.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub puzzle: Puzzle,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: PartStatus,
+    /// Execution time of a single run, or the mean execution time if the part was benched.
+    pub duration: Duration,
+    /// Benchmark statistics, only present if the part was benched.
+    pub stats: Option<BenchStats>,
+}
+
+impl PartResult {
+    /// Returns the result of a part that did not return, e.g. because it panicked or timed out.
+    pub fn failed(puzzle: Puzzle, part: u8, status: PartStatus, duration: Duration) -> Self {
+        PartResult {
+            puzzle,
+            part,
+            answer: None,
+            status,
+            duration,
+            stats: None,
+        }
+    }
+
+    /// Number of times the part was executed to measure its execution time.
+    pub fn samples(&self) -> u128 {
+        self.stats.map_or(1, |stats| stats.samples)
+    }
+
+    /// Serializes the result into a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        JsonValue::from(self)
+            .stringify()
+            .expect("part results are always valid JSON")
+    }
+
+    /// Parses a line printed with `--json`, returns `None` if the line is not a part record.
+    pub fn from_json_line(line: &str) -> Option<Self> {
+        let json = JsonValue::from_str(line.trim()).ok()?;
+        PartResult::try_from(&json).ok()
+    }
+
+    /// Prints the result in the same format as a solution binary does.
+    pub fn print(&self) {
+        match self.status {
+            PartStatus::Panicked | PartStatus::TimedOut => {
+                out!("\r");
+                outln!("Part {}: ✖ {}             ", self.part, self.status);
+            }
+            PartStatus::Solved | PartStatus::Unsolved => print_result(
+                &self.answer,
+                &format!("Part {}", self.part),
+                &format_duration(&self.duration, self.stats.as_ref()),
+            ),
+        }
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&PartResult> for JsonValue {
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::Number(f64::from(value.puzzle.year().into_inner())),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::Number(f64::from(value.puzzle.day().into_inner())),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(answer) => JsonValue::String(answer.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        map.insert("samples".into(), JsonValue::Number(value.samples() as f64));
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(stats) => JsonValue::from(stats),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+impl TryFrom<&JsonValue> for PartResult {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part result to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected part result.{key} to be a number."))
+        };
+
+        let puzzle = Year::new(number("year")? as u16)
+            .zip(Day::new(number("day")? as u8))
+            .and_then(|(year, day)| Puzzle::new(year, day))
+            .ok_or("Expected part result to reference a valid puzzle.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected part result.answer to be null or string.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected part result.status to be a string.")?
+            .parse::<PartStatus>()?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        if (status == PartStatus::Solved) != answer.is_some() {
+            return Err("Expected part result.answer to match its status.".into());
+        }
+
+        Ok(PartResult {
+            puzzle,
+            part: number("part")? as u8,
+            answer: answer.cloned(),
+            status,
+            duration: Duration::from_nanos(number("nanos")? as u64),
+            stats,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The outcome of running the parse stage of a solution, see [`run_parse`].
+/// With `--json`, solution binaries print this as a JSON line before the part records.
+#[derive(Clone, Debug)]
+pub struct ParseResult {
+    pub puzzle: Puzzle,
+    /// Execution time of a single run, or the mean execution time if the stage was benched.
+    pub duration: Duration,
+    /// Benchmark statistics, only present if the stage was benched.
+    pub stats: Option<BenchStats>,
+}
+
+impl ParseResult {
+    /// Serializes the result into a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        JsonValue::from(self)
+            .stringify()
+            .expect("parse results are always valid JSON")
+    }
+
+    /// Parses a line printed with `--json`, returns `None` if the line is not a parse record.
+    pub fn from_json_line(line: &str) -> Option<Self> {
+        let json = JsonValue::from_str(line.trim()).ok()?;
+        ParseResult::try_from(&json).ok()
+    }
+
+    /// Prints the result in the same format as a solution binary does.
+    pub fn print(&self) {
+        out!("\r");
+        outln!(
+            "Parse:{}",
+            format_duration(&self.duration, self.stats.as_ref())
+        );
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&ParseResult> for JsonValue {
+    fn from(value: &ParseResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::Number(f64::from(value.puzzle.year().into_inner())),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::Number(f64::from(value.puzzle.day().into_inner())),
+        );
+        map.insert("stage".into(), JsonValue::String("parse".into()));
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.map_or(1, |stats| stats.samples) as f64),
+        );
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(stats) => JsonValue::from(stats),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+impl TryFrom<&JsonValue> for ParseResult {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected parse result to be a JSON object.")?;
+
+        if json
+            .get("stage")
+            .and_then(|v| v.get::<String>())
+            .map(String::as_str)
+            != Some("parse")
+        {
+            return Err("Expected parse result.stage to be `parse`.".into());
+        }
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected parse result.{key} to be a number."))
+        };
+
+        let puzzle = Year::new(number("year")? as u16)
+            .zip(Day::new(number("day")? as u8))
+            .and_then(|(year, day)| Puzzle::new(year, day))
+            .ok_or("Expected parse result to reference a valid puzzle.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        Ok(ParseResult {
+            puzzle,
+            duration: Duration::from_nanos(number("nanos")? as u64),
+            stats,
+        })
+    }
+}
+
+/// The outcome of running a solution, i.e. its parse stage (if declared) and all of its parts.
+#[derive(Clone, Debug, Default)]
+pub struct RunResult {
+    pub parse: Option<ParseResult>,
+    pub parts: Vec<PartResult>,
+}
+
+impl RunResult {
+    /// Adds a result with the given status for every part that did not report one,
+    /// e.g. when a run was aborted before reaching part two.
+    pub fn fill_missing_parts(&mut self, puzzle: Puzzle, status: PartStatus) {
+        for part in 1..=2 {
+            if !self.parts.iter().any(|result| result.part == part) {
+                self.parts
+                    .push(PartResult::failed(puzzle, part, status, Duration::ZERO));
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+thread_local! {
+    /// Receives the result of every part that runs on the current thread, see [`report_parts`].
+    static PART_LISTENER: RefCell<Option<Sender<PartResult>>> = const { RefCell::new(None) };
+}
+
+/// Sends the result of every part that runs on the current thread to `sender` once the part finishes,
+/// so that the finished parts are known even if the solution as a whole does not finish.
+pub fn report_parts(sender: Sender<PartResult>) {
+    PART_LISTENER.with(|listener| *listener.borrow_mut() = Some(sender));
+}
+
+/// Run the parse stage of a solution. The parsed value is shared by all parts,
+/// so that the time spent parsing is reported separately from the time spent solving.
+pub fn run_parse<'a, T>(
+    func: impl Fn(&'a str) -> T,
+    input: &'a str,
+    puzzle: Puzzle,
+    options: &RunOptions,
+) -> (T, ParseResult) {
+    let (parsed, duration, stats) = run_timed(func, input, options.timed, |_| {
+        if !options.json && options.timed {
+            out!("Parse: > {ANSI_ITALIC}benching{ANSI_RESET}");
+        }
+    });
+
+    let parse_result = ParseResult {
+        puzzle,
+        duration,
+        stats,
+    };
+
+    if options.json {
+        outln!("{}", parse_result.to_json_line());
+    } else {
+        parse_result.print();
+    }
+
+    (parsed, parse_result)
+}
+
+/// Run a solution part. A panic is caught and reported as [`PartStatus::Panicked`],
+/// so that the remaining parts of the solution still run.
+pub fn run_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: Puzzle,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let timer = Instant::now();
+    let timed = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_timed(&func, input, options.timed, |result| {
+            if options.json {
+                return;
+            }
+            print_result(result, &part_str, "");
+            if options.timed {
+                out!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+            }
+        })
+    }));
+
+    let part_result = match timed {
+        Ok((result, duration, stats)) => {
+            let answer = result.map(|result| result.to_string());
+            PartResult {
+                puzzle,
+                part,
+                status: PartStatus::of(&answer),
+                answer,
+                duration,
+                stats,
+            }
+        }
+        Err(_) => PartResult::failed(puzzle, part, PartStatus::Panicked, timer.elapsed()),
+    };
+
+    PART_LISTENER.with(|listener| {
+        if let Some(sender) = listener.borrow().as_ref() {
+            let _ = sender.send(part_result.clone());
+        }
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    if options.json {
+        outln!("{}", part_result.to_json_line());
+    } else {
+        part_result.print();
+    }
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if let Some(answer) = &part_result.answer
+        && options.submit == Some(part)
+    {
+        submit::submit_or_queue(puzzle, part, answer);
     }
+
+    part_result
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. if not, the function is executed once.
+///  2. if it is, the function is benched (see [`bench`]) and the mean execution time is returned.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    timed: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, Option<BenchStats>) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
@@ -41,52 +631,18 @@ fn run_timed<I: Copy, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if timed {
+        let stats = bench(func, input, &base_time);
+        (result, stats.mean, Some(stats))
     } else {
-        (base_time, 1)
-    };
-
-    (result, run.0, run.1)
-}
-
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
-
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
-
-    let mut timers: Vec<Duration> = vec![];
-
-    for _ in 0..bench_iterations {
-        let timer = Instant::now();
-        black_box(func(black_box(input)));
-        timers.push(timer.elapsed());
+        (result, base_time, None)
     }
-
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
-}
-
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn format_duration(duration: &Duration, stats: Option<&BenchStats>) -> String {
+    match stats {
+        Some(stats) => format!(" ({stats})"),
+        None => format!(" ({duration:.1?})"),
     }
 }
 
@@ -98,70 +654,116 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    out!("\r");
+                    outln!("{str}");
+                    outln!("{result}");
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    out!("\r");
+                    outln!("{str}");
                 }
             }
         }
         None => {
             if is_intermediate_result {
-                print!("{part}: ✖");
+                out!("{part}: ✖");
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                out!("\r");
+                outln!("{part}: ✖             ");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/* -------------------------------------------------------------------------- */
 
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    }
+    use super::{InputSource, PartResult, PartStatus, RunResult};
+    use crate::puzzle;
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    fn parse(args: &str) -> Result<InputSource, String> {
+        let args: Vec<String> = args.split_whitespace().map(Into::into).collect();
+        InputSource::parse(&args)
+    }
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    };
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(parse("2025-05 --time"), Ok(InputSource::Puzzle));
+        assert_eq!(parse("2025-05 --example"), Ok(InputSource::Example(None)));
+        assert_eq!(
+            parse("2025-05 --example --time"),
+            Ok(InputSource::Example(None))
+        );
+        assert_eq!(
+            parse("2025-05 --example 2"),
+            Ok(InputSource::Example(Some(2)))
+        );
+        assert_eq!(
+            parse("2025-05 --input edge.txt"),
+            Ok(InputSource::File("edge.txt".into()))
+        );
+        assert_eq!(parse("2025-05 --input -"), Ok(InputSource::Stdin));
+        assert!(parse("2025-05 --input").is_err());
+    }
 
-    if part_submit != part {
-        return None;
+    #[test]
+    fn round_trips_input_sources() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Example(None),
+            InputSource::Example(Some(2)),
+            InputSource::File("edge.txt".into()),
+            InputSource::Stdin,
+        ] {
+            let mut args = vec!["2025-05".to_string()];
+            args.extend(source.to_args());
+            assert_eq!(InputSource::parse(&args), Ok(source));
+        }
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+    #[test]
+    fn round_trips_failed_parts() {
+        let result = PartResult::failed(
+            puzzle!(2025, 9),
+            2,
+            PartStatus::Panicked,
+            Duration::from_nanos(1_500),
         );
-        process::exit(1);
+        let parsed = PartResult::from_json_line(&result.to_json_line()).unwrap();
+        assert_eq!(parsed.status, PartStatus::Panicked);
+        assert_eq!(parsed.answer, None);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn fills_missing_parts() {
+        let mut result = RunResult {
+            parse: None,
+            parts: vec![PartResult {
+                puzzle: puzzle!(2025, 9),
+                part: 1,
+                answer: Some("42".into()),
+                status: PartStatus::Solved,
+                duration: Duration::from_nanos(1_500),
+                stats: None,
+            }],
+        };
+        result.fill_missing_parts(puzzle!(2025, 9), PartStatus::TimedOut);
+
+        let statuses: Vec<_> = result.parts.iter().map(|p| (p.part, p.status)).collect();
+        assert_eq!(
+            statuses,
+            vec![(1, PartStatus::Solved), (2, PartStatus::TimedOut)]
+        );
+    }
 }
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,373 @@
+/// Module that sniffs the shape of a puzzle input, so that `cargo scaffold` can suggest a parser for it.
+use std::fmt::Display;
+
+/// The detected shape of an input, or of a section of an input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputShape {
+    /// Lines of equal length without separators, e.g. `..@@.`, unless every line is an integer.
+    Grid { width: usize, height: usize },
+    /// Lines of comma-separated integers, e.g. `162,817,812`.
+    Coordinates { count: usize, dimensions: usize },
+    /// Ranges separated by commas or newlines, e.g. `11-22,95-115`.
+    Ranges { count: usize },
+    /// Lines that map a node to its neighbours, e.g. `aaa: you hhh`.
+    AdjacencyList { count: usize },
+    /// One integer per line.
+    Numbers { count: usize },
+    /// Sections separated by a blank line, each with its own shape.
+    Sections(Vec<InputShape>),
+    /// Anything else.
+    Lines { count: usize, width: usize },
+}
+
+impl InputShape {
+    /// Detects the shape of an input.
+    pub fn sniff(input: &str) -> Self {
+        let input = input.trim_end_matches('\n');
+        let sections: Vec<&str> = input.split("\n\n").collect();
+
+        if sections.len() > 1 {
+            InputShape::Sections(sections.into_iter().map(sniff_section).collect())
+        } else {
+            sniff_section(input)
+        }
+    }
+
+    /// Returns the suggested parse function along with the types and imports it needs,
+    /// or `None` if the shape is not recognized.
+    pub fn parser(&self) -> Option<String> {
+        let parser = match self {
+            InputShape::Lines { .. } => None,
+            InputShape::Sections(sections) => {
+                let mut helpers: Vec<String> = vec![];
+                let mut parsers = vec![];
+                for section in sections {
+                    let helper = section.helper();
+                    if !helpers.contains(&helper) {
+                        helpers.push(helper);
+                    }
+                    parsers.push(format!(
+                        "        parse_{}(sections.next().unwrap()),",
+                        section.kind()
+                    ));
+                }
+
+                let return_type = sections
+                    .iter()
+                    .map(InputShape::return_type)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                Some(format!(
+                    "{imports}/// Suggested parser, the input looks like {self}.\nfn parse(input: &str) -> ({return_type}) {{\n    let mut sections = input.split(\"\\n\\n\");\n    (\n{parsers}\n    )\n}}\n\n{helpers}",
+                    imports = self.imports(),
+                    parsers = parsers.join("\n"),
+                    helpers = helpers.join("\n"),
+                ))
+            }
+            _ => Some(format!(
+                "{imports}/// Suggested parser, the input looks like {self}.\n{parser}",
+                imports = self.imports(),
+                parser = self.body("parse"),
+            )),
+        };
+
+        parser.map(|parser| parser.trim_end().to_string())
+    }
+
+    /// Name of the shape, used to name the parse function of a section.
+    fn kind(&self) -> &'static str {
+        match self {
+            InputShape::Grid { .. } => "grid",
+            InputShape::Coordinates { .. } => "coordinates",
+            InputShape::Ranges { .. } => "ranges",
+            InputShape::AdjacencyList { .. } => "adjacency_list",
+            InputShape::Numbers { .. } => "numbers",
+            InputShape::Sections(_) => "sections",
+            InputShape::Lines { .. } => "lines",
+        }
+    }
+
+    fn return_type(&self) -> String {
+        match self {
+            InputShape::Grid { .. } => "Grid".into(),
+            InputShape::Coordinates { dimensions, .. } => format!("Vec<[i64; {dimensions}]>"),
+            InputShape::Ranges { .. } => "Vec<(u64, u64)>".into(),
+            InputShape::AdjacencyList { .. } => "HashMap<&str, Vec<&str>>".into(),
+            InputShape::Numbers { .. } => "Vec<i64>".into(),
+            InputShape::Sections(_) | InputShape::Lines { .. } => "Vec<&str>".into(),
+        }
+    }
+
+    fn imports(&self) -> String {
+        let mut imports = String::new();
+        if self.contains(|shape| matches!(shape, InputShape::Grid { .. })) {
+            imports.push_str("use advent_of_code::Grid;\n");
+        }
+        if self.contains(|shape| matches!(shape, InputShape::AdjacencyList { .. })) {
+            imports.push_str("use std::collections::HashMap;\n");
+        }
+        if !imports.is_empty() {
+            imports.push('\n');
+        }
+        imports
+    }
+
+    fn contains(&self, predicate: impl Fn(&InputShape) -> bool) -> bool {
+        match self {
+            InputShape::Sections(sections) => sections.iter().any(predicate),
+            shape => predicate(shape),
+        }
+    }
+
+    /// Returns the parse function of a section, named after its kind.
+    fn helper(&self) -> String {
+        format!(
+            "/// Parses {}.\n{}",
+            self.kind().replace('_', " "),
+            self.body(&format!("parse_{}", self.kind()))
+        )
+    }
+
+    fn body(&self, name: &str) -> String {
+        let return_type = self.return_type();
+        let body = match self {
+            InputShape::Grid { .. } => "    Grid::new(input)".into(),
+            InputShape::Coordinates { .. } => "    input
+        .lines()
+        .map(|line| {
+            let mut values = line.split(',').map(|value| value.trim().parse().unwrap());
+            std::array::from_fn(|_| values.next().unwrap())
+        })
+        .collect()"
+                .into(),
+            InputShape::Ranges { .. } => "    input
+        .split([',', '\\n'])
+        .filter(|range| !range.trim().is_empty())
+        .map(|range| {
+            let (start, end) = range.trim().split_once('-').unwrap();
+            (start.parse().unwrap(), end.parse().unwrap())
+        })
+        .collect()"
+                .into(),
+            InputShape::AdjacencyList { .. } => "    input
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(from, to)| (from.trim(), to.split_whitespace().collect()))
+        .collect()"
+                .into(),
+            InputShape::Numbers { .. } => {
+                "    input.lines().map(|line| line.trim().parse().unwrap()).collect()".into()
+            }
+            InputShape::Sections(_) | InputShape::Lines { .. } => {
+                "    input.lines().collect()".to_string()
+            }
+        };
+
+        format!("fn {name}(input: &str) -> {return_type} {{\n{body}\n}}\n")
+    }
+}
+
+impl Display for InputShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputShape::Grid { width, height } => write!(f, "a grid of {width}x{height} cells"),
+            InputShape::Coordinates { count, dimensions } => {
+                write!(f, "{count} coordinates with {dimensions} dimensions")
+            }
+            InputShape::Ranges { count } => write!(f, "{count} ranges"),
+            InputShape::AdjacencyList { count } => {
+                write!(f, "an adjacency list of {count} nodes")
+            }
+            InputShape::Numbers { count } => write!(f, "{count} numbers"),
+            InputShape::Sections(sections) => {
+                let sections: Vec<String> = sections.iter().map(ToString::to_string).collect();
+                write!(f, "{} sections: {}", sections.len(), sections.join(" and "))
+            }
+            InputShape::Lines { count: 0, .. } => write!(f, "an empty input"),
+            InputShape::Lines { count: 1, width } => write!(f, "1 line of {width} characters"),
+            InputShape::Lines { count, width } => {
+                write!(f, "{count} lines of up to {width} characters")
+            }
+        }
+    }
+}
+
+fn sniff_section(section: &str) -> InputShape {
+    let lines: Vec<&str> = section.lines().collect();
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+
+    if lines.is_empty() || width == 0 {
+        return InputShape::Lines {
+            count: lines.len(),
+            width,
+        };
+    }
+
+    // numbers come first, since a list of integers with the same number of digits has the shape of a grid.
+    // rows of digits that are too long for an `i64` are read digit by digit, so they are left to the grid check.
+    let is_number = |line: &&str| is_integer(line.trim()) && line.trim().parse::<i64>().is_ok();
+    if lines.iter().all(is_number) {
+        return InputShape::Numbers { count: lines.len() };
+    }
+
+    let is_grid = lines.len() > 1
+        && width > 1
+        && lines
+            .iter()
+            .all(|line| line.len() == width && !line.contains([',', ' ', ':']) && line.is_ascii());
+    if is_grid {
+        return InputShape::Grid {
+            width,
+            height: lines.len(),
+        };
+    }
+
+    let ranges: Vec<&str> = section
+        .split([',', '\n'])
+        .map(str::trim)
+        .filter(|range| !range.is_empty())
+        .collect();
+    if ranges.iter().all(|range| is_range(range)) {
+        return InputShape::Ranges {
+            count: ranges.len(),
+        };
+    }
+
+    if lines.iter().all(|line| is_adjacency(line)) {
+        return InputShape::AdjacencyList { count: lines.len() };
+    }
+
+    let dimensions = lines[0].split(',').count();
+    if dimensions > 1
+        && lines.iter().all(|line| {
+            let values: Vec<&str> = line.split(',').map(str::trim).collect();
+            values.len() == dimensions && values.iter().all(|value| is_integer(value))
+        })
+    {
+        return InputShape::Coordinates {
+            count: lines.len(),
+            dimensions,
+        };
+    }
+
+    InputShape::Lines {
+        count: lines.len(),
+        width,
+    }
+}
+
+fn is_integer(s: &str) -> bool {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_range(s: &str) -> bool {
+    s.split_once('-').is_some_and(|(start, end)| {
+        !start.is_empty() && is_integer(start) && is_integer(end) && !end.starts_with('-')
+    })
+}
+
+fn is_adjacency(line: &str) -> bool {
+    let is_name = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphanumeric());
+    line.split_once(':').is_some_and(|(from, to)| {
+        is_name(from) && !to.trim().is_empty() && to.split_whitespace().all(is_name)
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputShape;
+
+    #[test]
+    fn sniffs_grids() {
+        let shape = InputShape::sniff("..@@.\n@@@.@\n.....\n");
+        assert_eq!(
+            shape,
+            InputShape::Grid {
+                width: 5,
+                height: 3
+            }
+        );
+        assert_eq!(shape.to_string(), "a grid of 5x3 cells");
+    }
+
+    #[test]
+    fn tells_numbers_from_rows_of_digits() {
+        let shape = InputShape::sniff("199\n200\n208\n");
+        assert_eq!(shape, InputShape::Numbers { count: 3 });
+
+        let shape = InputShape::sniff("98765432111111111111\n81111111111111111119\n");
+        assert_eq!(
+            shape,
+            InputShape::Grid {
+                width: 20,
+                height: 2
+            }
+        );
+    }
+
+    #[test]
+    fn sniffs_coordinates() {
+        let shape = InputShape::sniff("162,817,812\n57,618,57\n906,360,560\n");
+        assert_eq!(
+            shape,
+            InputShape::Coordinates {
+                count: 3,
+                dimensions: 3
+            }
+        );
+        assert!(
+            shape
+                .parser()
+                .unwrap()
+                .contains("fn parse(input: &str) -> Vec<[i64; 3]>")
+        );
+    }
+
+    #[test]
+    fn sniffs_ranges() {
+        assert_eq!(
+            InputShape::sniff("11-22,95-115,998-1012\n"),
+            InputShape::Ranges { count: 3 }
+        );
+    }
+
+    #[test]
+    fn sniffs_adjacency_lists() {
+        let shape = InputShape::sniff("aaa: you hhh\nyou: bbb ccc\n");
+        assert_eq!(shape, InputShape::AdjacencyList { count: 2 });
+        let parser = shape.parser().unwrap();
+        assert!(parser.starts_with("use std::collections::HashMap;\n\n"));
+        assert!(parser.contains("fn parse(input: &str) -> HashMap<&str, Vec<&str>>"));
+    }
+
+    #[test]
+    fn sniffs_sections() {
+        let shape = InputShape::sniff("3-5\n10-14\n\n1\n5\n8\n");
+        assert_eq!(
+            shape,
+            InputShape::Sections(vec![
+                InputShape::Ranges { count: 2 },
+                InputShape::Numbers { count: 3 }
+            ])
+        );
+        assert_eq!(shape.to_string(), "2 sections: 2 ranges and 3 numbers");
+
+        let parser = shape.parser().unwrap();
+        assert!(parser.contains("fn parse(input: &str) -> (Vec<(u64, u64)>, Vec<i64>)"));
+        assert!(parser.contains("fn parse_ranges(input: &str) -> Vec<(u64, u64)>"));
+        assert!(parser.contains("fn parse_numbers(input: &str) -> Vec<i64>"));
+    }
+
+    #[test]
+    fn falls_back_to_lines() {
+        let shape = InputShape::sniff("L68\nL5\nR148\n");
+        assert_eq!(shape, InputShape::Lines { count: 3, width: 4 });
+        assert_eq!(shape.parser(), None);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
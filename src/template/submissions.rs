@@ -0,0 +1,449 @@
+/// Module that keeps a log of submitted answers, so that known-wrong answers are not submitted twice.
+use std::{
+    collections::HashMap, fmt::Display, fs, io, path::PathBuf, str::FromStr, time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Puzzle, timings::current_date};
+
+/// How the advent of code website judged a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint in which direction.
+    Wrong,
+    /// The part has already been solved, the answer was not checked.
+    AlreadySolved,
+    /// An answer was submitted too recently, the answer was not checked.
+    RateLimited(Duration),
+}
+
+impl Outcome {
+    /// Classifies the response to a submission, returns `None` if the response is not recognized.
+    pub fn from_response(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if response.contains("You gave an answer too recently") {
+            Some(Outcome::RateLimited(parse_wait(response)))
+        } else if response.contains("Did you already complete it") {
+            Some(Outcome::AlreadySolved)
+        } else if response.contains("That's not the right answer") {
+            Some(if response.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if response.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the website rejected the answer.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wrong => "wrong",
+            Outcome::AlreadySolved => "already_solved",
+            Outcome::RateLimited(_) => "rate_limited",
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "✔ correct"),
+            Outcome::TooHigh => write!(f, "✖ too high"),
+            Outcome::TooLow => write!(f, "✖ too low"),
+            Outcome::Wrong => write!(f, "✖ wrong"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::RateLimited(wait) => write!(f, "rate limited, wait {wait:?}"),
+        }
+    }
+}
+
+/// Parses the wait time of a rate-limited response, e.g. "You have 1m 5s left to wait."
+fn parse_wait(response: &str) -> Duration {
+    let wait = response
+        .split("You have ")
+        .nth(1)
+        .and_then(|rest| rest.split(" left to wait").next())
+        .map(|wait| {
+            wait.split_whitespace()
+                .map(|token| {
+                    let mut chars = token.chars();
+                    let unit = chars.next_back();
+                    let value: u64 = chars.as_str().parse().unwrap_or(0);
+                    // tokens that are not a number followed by a unit are ignored.
+                    match unit {
+                        Some('h') => value * 3600,
+                        Some('m') => value * 60,
+                        Some('s') => value,
+                        _ => 0,
+                    }
+                })
+                .sum()
+        });
+
+    // NOTE: the website asks for a wait of at least a minute after a wrong answer.
+    Duration::from_secs(wait.unwrap_or(60))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A single submitted answer and how it was judged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// UTC date of the submission, e.g. `2025-12-01T05:00:00Z`.
+    pub date: String,
+}
+
+impl Submission {
+    pub fn now(part: u8, answer: &str, outcome: Outcome) -> Self {
+        Submission {
+            part,
+            answer: answer.into(),
+            outcome,
+            date: current_date(),
+        }
+    }
+}
+
+/// The reason an answer is not submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The same answer has been rejected before.
+    KnownWrong(Outcome),
+    /// An answer lower or equal to this one was too high.
+    TooHigh(String),
+    /// An answer higher or equal to this one was too low.
+    TooLow(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::KnownWrong(outcome) => {
+                write!(f, "it was submitted before and judged {outcome}")
+            }
+            Refusal::TooHigh(bound) => write!(f, "{bound} was already too high"),
+            Refusal::TooLow(bound) => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+/// All submissions of a puzzle, stored in `data/submissions/<year>/<day>.json`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    pub entries: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Reads the submissions of a puzzle. If nothing has been submitted yet, returns an empty log.
+    pub fn read(puzzle: Puzzle) -> Result<Self, String> {
+        match fs::read_to_string(get_submissions_path(puzzle)) {
+            Ok(contents) => Submissions::from_str(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Writes the submissions of a puzzle to disk.
+    pub fn store(&self, puzzle: Puzzle) -> Result<(), io::Error> {
+        let path = get_submissions_path(puzzle);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
+        JsonValue::from(self).format_to(&mut file)
+    }
+
+    /// Appends a submission to the log of a puzzle and writes it to disk.
+    pub fn record(puzzle: Puzzle, submission: Submission) -> Result<(), String> {
+        let mut submissions = Submissions::read(puzzle)?;
+        submissions.entries.push(submission);
+        submissions.store(puzzle).map_err(|e| e.to_string())
+    }
+
+    /// Checks whether an answer is worth submitting, given the earlier submissions of the part.
+    /// Numeric answers are refused if they fall outside the bracket of too high / too low answers.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Refusal> {
+        let entries = self.entries.iter().filter(|entry| entry.part == part);
+
+        if let Some(entry) = entries
+            .clone()
+            .find(|entry| entry.answer == answer && entry.outcome.is_wrong())
+        {
+            return Err(Refusal::KnownWrong(entry.outcome));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |outcome: Outcome| {
+            entries
+                .clone()
+                .filter(move |entry| entry.outcome == outcome)
+                .filter_map(|entry| Some((entry.answer.parse::<i128>().ok()?, &entry.answer)))
+        };
+
+        if let Some((_, high)) = bound(Outcome::TooHigh)
+            .filter(|(high, _)| value >= *high)
+            .min()
+        {
+            return Err(Refusal::TooHigh(high.clone()));
+        }
+
+        if let Some((_, low)) = bound(Outcome::TooLow)
+            .filter(|(low, _)| value <= *low)
+            .max()
+        {
+            return Err(Refusal::TooLow(low.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+fn get_submissions_path(puzzle: Puzzle) -> PathBuf {
+    PathBuf::from("data")
+        .join("submissions")
+        .join(puzzle.year().to_string())
+        .join(format!("{}.json", puzzle.day()))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submissions> for JsonValue {
+    fn from(value: &Submissions) -> Self {
+        JsonValue::Array(value.entries.iter().map(JsonValue::from).collect())
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(value.outcome.as_str().into()),
+        );
+        map.insert("date".into(), JsonValue::String(value.date.clone()));
+
+        if let Outcome::RateLimited(wait) = value.outcome {
+            map.insert(
+                "wait_seconds".into(),
+                JsonValue::Number(wait.as_secs() as f64),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for Submissions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON file."))?;
+
+        let entries = json
+            .get::<Vec<JsonValue>>()
+            .ok_or("Expected submissions to be a JSON array.")?;
+
+        Ok(Submissions {
+            entries: entries
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .cloned()
+                .ok_or(format!("Expected submission.{key} to be a string."))
+        };
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("Expected submission.{key} to be a number."))
+        };
+
+        let outcome = match string("outcome")?.as_str() {
+            "correct" => Outcome::Correct,
+            "too_high" => Outcome::TooHigh,
+            "too_low" => Outcome::TooLow,
+            "wrong" => Outcome::Wrong,
+            "already_solved" => Outcome::AlreadySolved,
+            "rate_limited" => {
+                Outcome::RateLimited(Duration::from_secs(number("wait_seconds")? as u64))
+            }
+            other => return Err(format!("Unknown submission outcome: {other}.")),
+        };
+
+        Ok(Submission {
+            part: number("part")? as u8,
+            answer: string("answer")?,
+            outcome,
+            date: string("date")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use tinyjson::JsonValue;
+
+    use super::{Outcome, Refusal, Submission, Submissions};
+
+    fn submission(part: u8, answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            part,
+            answer: answer.into(),
+            outcome,
+            date: "2025-12-01T05:00:00Z".into(),
+        }
+    }
+
+    #[test]
+    fn classifies_responses() {
+        let cases = [
+            (
+                "That's the right answer!  You are one gold star closer to decorating the North Pole.",
+                Some(Outcome::Correct),
+            ),
+            (
+                "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+                Some(Outcome::TooHigh),
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Some(Outcome::TooLow),
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+                Some(Outcome::Wrong),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Some(Outcome::AlreadySolved),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+                Some(Outcome::RateLimited(Duration::from_secs(65))),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 39s left to wait.",
+                Some(Outcome::RateLimited(Duration::from_secs(39))),
+            ),
+            (
+                "You gave an answer too recently.  You have 2m 5s… left to wait.",
+                Some(Outcome::RateLimited(Duration::from_secs(120))),
+            ),
+            ("Please log in.", None),
+        ];
+
+        for (response, outcome) in cases {
+            assert_eq!(Outcome::from_response(response), outcome, "{response}");
+        }
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let submissions = Submissions {
+            entries: vec![
+                submission(1, "abc", Outcome::Wrong),
+                submission(1, "def", Outcome::RateLimited(Duration::from_secs(30))),
+            ],
+        };
+        assert_eq!(
+            submissions.check(1, "abc"),
+            Err(Refusal::KnownWrong(Outcome::Wrong))
+        );
+        assert_eq!(submissions.check(2, "abc"), Ok(()));
+        assert_eq!(submissions.check(1, "def"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_outside_bracket() {
+        let submissions = Submissions {
+            entries: vec![
+                submission(1, "100", Outcome::TooHigh),
+                submission(1, "80", Outcome::TooHigh),
+                submission(1, "10", Outcome::TooLow),
+                submission(1, "20", Outcome::TooLow),
+                submission(2, "5", Outcome::TooHigh),
+            ],
+        };
+        assert_eq!(submissions.check(1, "50"), Ok(()));
+        assert_eq!(
+            submissions.check(1, "80"),
+            Err(Refusal::KnownWrong(Outcome::TooHigh))
+        );
+        assert_eq!(
+            submissions.check(1, "90"),
+            Err(Refusal::TooHigh("80".into()))
+        );
+        assert_eq!(
+            submissions.check(1, "15"),
+            Err(Refusal::TooLow("20".into()))
+        );
+        assert_eq!(
+            submissions.check(1, "-3"),
+            Err(Refusal::TooLow("20".into()))
+        );
+        assert_eq!(submissions.check(1, "not a number"), Ok(()));
+        assert_eq!(submissions.check(2, "4"), Ok(()));
+    }
+
+    #[test]
+    fn round_trips_submissions() {
+        let submissions = Submissions {
+            entries: vec![
+                submission(1, "42", Outcome::TooLow),
+                submission(1, "43", Outcome::RateLimited(Duration::from_secs(61))),
+                submission(2, "ABC", Outcome::Correct),
+            ],
+        };
+        let json = JsonValue::from(&submissions).stringify().unwrap();
+        assert_eq!(Submissions::from_str(&json).unwrap(), submissions);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
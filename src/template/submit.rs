@@ -0,0 +1,317 @@
+/// Submits answers to the advent of code website, or queues them while the website can't be reached.
+use std::{collections::HashMap, fmt::Display, fs, io, path::PathBuf, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::{Backend, BackendError};
+use crate::template::timings::current_date;
+use crate::template::{Answers, Day, Outcome, Puzzle, Submission, Submissions, Year};
+
+/// What happened to an answer passed to [`submit`].
+#[derive(Debug)]
+pub enum Attempt {
+    /// Earlier submissions show that the answer is wrong, it was not submitted.
+    Refused,
+    Judged(Outcome),
+    /// The website responded with a message that could not be classified.
+    Unrecognized,
+    Failed(BackendError),
+}
+
+/// Submits an answer, unless earlier submissions show that it is wrong.
+/// The outcome is recorded in the submission log of the puzzle, correct answers are also recorded for `cargo verify`.
+pub fn submit(backend: &Backend, puzzle: Puzzle, part: u8, answer: &str) -> Attempt {
+    let submissions = Submissions::read(puzzle).unwrap_or_else(|e| {
+        eprintln!("Could not read submissions of {puzzle}: {e}");
+        Submissions::default()
+    });
+
+    if let Err(refusal) = submissions.check(part, answer) {
+        eprintln!("Not submitting {answer}: {refusal}.");
+        return Attempt::Refused;
+    }
+
+    println!("Submitting {answer} for {puzzle} part {part} via {backend}...");
+    let response = match backend.submit(puzzle, part, answer) {
+        Ok(response) => response,
+        Err(e) => return Attempt::Failed(e),
+    };
+
+    let Some(outcome) = Outcome::from_response(&response) else {
+        eprintln!("Could not classify the response, the submission was not recorded.");
+        return Attempt::Unrecognized;
+    };
+
+    println!("Submission: {outcome}");
+
+    if let Err(e) = Submissions::record(puzzle, Submission::now(part, answer, outcome)) {
+        eprintln!("failed to record submission: {e}");
+    }
+
+    if outcome == Outcome::Correct {
+        match Answers::record(puzzle, part, answer) {
+            Ok(()) => println!("Recorded answer for `cargo verify`."),
+            Err(e) => eprintln!("failed to record answer: {e}"),
+        }
+    }
+
+    Attempt::Judged(outcome)
+}
+
+/// Submits an answer with the detected backend.
+/// If no backend is available, the request fails or is rate-limited, the answer is queued for `cargo submit --flush`.
+pub fn submit_or_queue(puzzle: Puzzle, part: u8, answer: &str) {
+    let attempt = match Backend::detect() {
+        Ok(backend) => submit(&backend, puzzle, part, answer),
+        Err(e) => Attempt::Failed(e),
+    };
+
+    match attempt {
+        Attempt::Failed(e) => eprintln!("{e}"),
+        Attempt::Judged(Outcome::RateLimited(_)) => {}
+        _ => return,
+    }
+
+    let result = Queue::read().and_then(|mut queue| {
+        let is_new = queue.push(QueuedSubmission::now(puzzle, part, answer));
+        queue.store().map_err(|e| e.to_string())?;
+        Ok(is_new)
+    });
+
+    match result {
+        Ok(true) => println!("Queued {answer}, run `cargo submit --flush` to submit it later."),
+        Ok(false) => println!("{answer} is already queued."),
+        Err(e) => eprintln!("failed to queue submission: {e}"),
+    }
+}
+
+/// Replays queued submissions in order and returns the submissions that remain queued.
+/// Stops at the first submission that fails or is rate-limited, it stays queued together with all later ones.
+pub fn flush(backend: &Backend, queue: &Queue) -> Queue {
+    for (index, queued) in queue.entries.iter().enumerate() {
+        let should_stop = match submit(backend, queued.puzzle, queued.part, &queued.answer) {
+            Attempt::Failed(e) => {
+                eprintln!("{e}");
+                true
+            }
+            Attempt::Judged(Outcome::RateLimited(_)) => true,
+            Attempt::Refused | Attempt::Judged(_) | Attempt::Unrecognized => false,
+        };
+
+        if should_stop {
+            return Queue {
+                entries: queue.entries[index..].to_vec(),
+            };
+        }
+    }
+
+    Queue::default()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An answer waiting to be submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueuedSubmission {
+    pub puzzle: Puzzle,
+    pub part: u8,
+    pub answer: String,
+    /// UTC date the answer was queued at, e.g. `2025-12-01T05:00:00Z`.
+    pub date: String,
+}
+
+impl QueuedSubmission {
+    pub fn now(puzzle: Puzzle, part: u8, answer: &str) -> Self {
+        QueuedSubmission {
+            puzzle,
+            part,
+            answer: answer.into(),
+            date: current_date(),
+        }
+    }
+}
+
+impl Display for QueuedSubmission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} Part {}: {} (queued {})",
+            self.puzzle, self.part, self.answer, self.date
+        )
+    }
+}
+
+/// Answers waiting to be submitted, stored in `data/submissions/queue.json`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Queue {
+    pub entries: Vec<QueuedSubmission>,
+}
+
+impl Queue {
+    /// Reads the queue. If nothing has been queued yet, returns an empty queue.
+    pub fn read() -> Result<Self, String> {
+        match fs::read_to_string(get_queue_path()) {
+            Ok(contents) => Queue::from_str(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Queue::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Writes the queue to disk.
+    pub fn store(&self) -> Result<(), io::Error> {
+        let path = get_queue_path();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
+        JsonValue::from(self).format_to(&mut file)
+    }
+
+    /// Appends a submission to the queue, returns `false` if the same answer is already queued.
+    pub fn push(&mut self, submission: QueuedSubmission) -> bool {
+        let is_queued = self.entries.iter().any(|entry| {
+            entry.puzzle == submission.puzzle
+                && entry.part == submission.part
+                && entry.answer == submission.answer
+        });
+
+        if !is_queued {
+            self.entries.push(submission);
+        }
+
+        !is_queued
+    }
+}
+
+fn get_queue_path() -> PathBuf {
+    PathBuf::from("data").join("submissions").join("queue.json")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Queue> for JsonValue {
+    fn from(value: &Queue) -> Self {
+        JsonValue::Array(value.entries.iter().map(JsonValue::from).collect())
+    }
+}
+
+impl From<&QueuedSubmission> for JsonValue {
+    fn from(value: &QueuedSubmission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::Number(f64::from(value.puzzle.year().into_inner())),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::Number(f64::from(value.puzzle.day().into_inner())),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert("date".into(), JsonValue::String(value.date.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for Queue {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON file."))?;
+
+        let entries = json
+            .get::<Vec<JsonValue>>()
+            .ok_or("Expected queue to be a JSON array.")?;
+
+        Ok(Queue {
+            entries: entries
+                .iter()
+                .map(QueuedSubmission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+impl TryFrom<&JsonValue> for QueuedSubmission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected queued submission to be a JSON object.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .cloned()
+                .ok_or(format!("Expected queued submission.{key} to be a string."))
+        };
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("Expected queued submission.{key} to be a number."))
+        };
+
+        let puzzle = Year::new(number("year")? as u16)
+            .zip(Day::new(number("day")? as u8))
+            .and_then(|(year, day)| Puzzle::new(year, day))
+            .ok_or("Expected queued submission.year and .day to form a valid puzzle.")?;
+
+        Ok(QueuedSubmission {
+            puzzle,
+            part: number("part")? as u8,
+            answer: string("answer")?,
+            date: string("date")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use super::{Queue, QueuedSubmission};
+    use crate::{puzzle, template::Puzzle};
+
+    fn queued(puzzle: Puzzle, part: u8, answer: &str) -> QueuedSubmission {
+        QueuedSubmission {
+            puzzle,
+            part,
+            answer: answer.into(),
+            date: "2025-12-01T05:00:00Z".into(),
+        }
+    }
+
+    #[test]
+    fn skips_queued_answers() {
+        let mut queue = Queue::default();
+        assert!(queue.push(queued(puzzle!(2025, 1), 1, "42")));
+        assert!(queue.push(queued(puzzle!(2025, 1), 2, "42")));
+        assert!(queue.push(queued(puzzle!(2025, 2), 1, "42")));
+        assert!(!queue.push(queued(puzzle!(2025, 1), 1, "42")));
+        assert_eq!(queue.entries.len(), 3);
+    }
+
+    #[test]
+    fn round_trips_queue() {
+        let queue = Queue {
+            entries: vec![
+                queued(puzzle!(2025, 1), 1, "42"),
+                queued(puzzle!(2025, 12), 2, "abc"),
+            ],
+        };
+        let json = JsonValue::from(&queue).stringify().unwrap();
+        assert_eq!(Queue::from_str(&json).unwrap(), queue);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,226 @@
+/// Module that loads and renders the module templates used by `cargo scaffold`.
+/// Templates live in `src/templates/<name>.txt`, adding a file there makes it selectable with `--template <name>`.
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+use crate::template::{Examples, InputShape, Puzzle};
+
+pub const TEMPLATES_DIR: &str = "src/templates";
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+#[derive(Debug)]
+pub enum TemplateError {
+    NotFound {
+        name: String,
+        available: Vec<String>,
+    },
+    IO(io::Error),
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::NotFound { name, available } => write!(
+                f,
+                "template \"{name}\" does not exist in \"{TEMPLATES_DIR}\", available templates: {}.",
+                available.join(", ")
+            ),
+            TemplateError::IO(e) => write!(f, "could not read template: {e}"),
+        }
+    }
+}
+
+/// Reads the template with the given name.
+pub fn load(name: &str) -> Result<String, TemplateError> {
+    match fs::read_to_string(get_template_path(name)) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(TemplateError::NotFound {
+            name: name.into(),
+            available: available(),
+        }),
+        Err(e) => Err(TemplateError::IO(e)),
+    }
+}
+
+/// Returns the names of all templates, sorted alphabetically.
+pub fn available() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_string_lossy().into_owned())
+        })
+        .collect();
+    names.sort_unstable();
+    names
+}
+
+fn get_template_path(name: &str) -> PathBuf {
+    PathBuf::from(TEMPLATES_DIR).join(format!("{name}.txt"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The values that are substituted for the `%PLACEHOLDER%`s of a template.
+pub struct Placeholders {
+    values: Vec<(&'static str, String)>,
+}
+
+impl Placeholders {
+    /// Collects the placeholder values of a puzzle.
+    /// The description and input are optional, since they may not have been downloaded yet.
+    pub fn new(
+        puzzle: Puzzle,
+        description: Option<&str>,
+        examples: &Examples,
+        input: Option<&str>,
+    ) -> Self {
+        let day = puzzle.day();
+        let shape = input.map(InputShape::sniff);
+        let answer = |part: u8| {
+            examples
+                .get(part)
+                .and_then(|example| example.answer.clone())
+                .unwrap_or_else(|| "unknown".into())
+        };
+
+        Placeholders {
+            values: vec![
+                ("%DAY_NUMBER%", day.into_inner().to_string()),
+                ("%DAY%", day.to_string()),
+                ("%YEAR%", puzzle.year().to_string()),
+                (
+                    "%TITLE%",
+                    description
+                        .and_then(title)
+                        .unwrap_or_else(|| format!("Day {}", day.into_inner())),
+                ),
+                ("%PART_ONE_ANSWER%", answer(1)),
+                ("%PART_TWO_ANSWER%", answer(2)),
+                (
+                    "%INPUT_SHAPE%",
+                    shape
+                        .as_ref()
+                        .map_or_else(|| "not downloaded yet".into(), ToString::to_string),
+                ),
+                (
+                    "%PARSER%",
+                    shape
+                        .as_ref()
+                        .and_then(InputShape::parser)
+                        .unwrap_or_default(),
+                ),
+            ],
+        }
+    }
+
+    /// Replaces every placeholder in the template.
+    /// A placeholder on a line of its own is removed along with the following blank line if it is empty.
+    pub fn render(&self, template: &str) -> String {
+        self.values
+            .iter()
+            .fold(template.to_string(), |rendered, (placeholder, value)| {
+                let rendered = if value.is_empty() {
+                    rendered.replace(&format!("{placeholder}\n\n"), "")
+                } else {
+                    rendered
+                };
+                rendered.replace(placeholder, value)
+            })
+    }
+
+    /// Returns `true` if a parser was suggested for the input, but the template has no `%PARSER%` placeholder for it.
+    pub fn drops_parser(&self, template: &str) -> bool {
+        let has_parser = self
+            .values
+            .iter()
+            .any(|(placeholder, value)| *placeholder == "%PARSER%" && !value.is_empty());
+        has_parser && !template.contains("%PARSER%")
+    }
+}
+
+/// Returns the title of a puzzle description, e.g. `Secret Entrance` for `## --- Day 1: Secret Entrance ---`.
+fn title(description: &str) -> Option<String> {
+    let heading = description.lines().find(|line| line.contains("--- Day "))?;
+    let (_, title) = heading.split_once(':')?;
+    Some(title.trim().trim_end_matches('-').trim().to_string()).filter(|title| !title.is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Placeholders, title};
+    use crate::template::{Example, Examples};
+
+    #[test]
+    fn extracts_titles() {
+        assert_eq!(
+            title("## --- Day 1: Secret Entrance ---\n\nThe Elves...").as_deref(),
+            Some("Secret Entrance")
+        );
+        assert_eq!(
+            title("\\--- Day 12: Christmas Tree Farm ---").as_deref(),
+            Some("Christmas Tree Farm")
+        );
+        assert_eq!(title("No heading"), None);
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let examples = Examples {
+            part_1: Some(Example {
+                input: "L68\n".into(),
+                answer: Some("3".into()),
+            }),
+            part_2: None,
+        };
+        let placeholders = Placeholders::new(
+            crate::puzzle!(2025, 1),
+            Some("## --- Day 1: Secret Entrance ---"),
+            &examples,
+            None,
+        );
+
+        assert_eq!(
+            placeholders.render("solution!(%DAY_NUMBER%, year = %YEAR%); // %DAY%: %TITLE%, %PART_ONE_ANSWER%/%PART_TWO_ANSWER%, %INPUT_SHAPE%"),
+            "solution!(1, year = 2025); // 01: Secret Entrance, 3/unknown, not downloaded yet"
+        );
+    }
+
+    #[test]
+    fn renders_suggested_parsers() {
+        let template = "solution!(%DAY_NUMBER%);\n\n%PARSER%\n\npub fn part_one() {}\n";
+        let puzzle = crate::puzzle!(2025, 4);
+
+        let placeholders =
+            Placeholders::new(puzzle, None, &Examples::default(), Some("..@\n@@.\n"));
+        assert_eq!(
+            placeholders.render(template),
+            "solution!(4);\n\nuse advent_of_code::Grid;\n\n/// Suggested parser, the input looks like a grid of 3x2 cells.\nfn parse(input: &str) -> Grid {\n    Grid::new(input)\n}\n\npub fn part_one() {}\n"
+        );
+
+        let placeholders = Placeholders::new(puzzle, None, &Examples::default(), None);
+        assert_eq!(
+            placeholders.render(template),
+            "solution!(4);\n\npub fn part_one() {}\n"
+        );
+    }
+
+    #[test]
+    fn detects_dropped_parsers() {
+        let puzzle = crate::puzzle!(2025, 4);
+        let placeholders =
+            Placeholders::new(puzzle, None, &Examples::default(), Some("..@\n@@.\n"));
+        assert!(placeholders.drops_parser("solution!(%DAY_NUMBER%);\n"));
+        assert!(!placeholders.drops_parser("%PARSER%\n"));
+
+        let placeholders = Placeholders::new(puzzle, None, &Examples::default(), None);
+        assert!(!placeholders.drops_parser("solution!(%DAY_NUMBER%);\n"));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
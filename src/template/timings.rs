@@ -1,24 +1,168 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::Error,
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{BenchStats, Day, Puzzle, Year};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
-/// Represents benchmark times for a single day.
+/// Represents benchmark times for a single puzzle.
 #[derive(Clone, Debug)]
 pub struct Timing {
-    pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub puzzle: Puzzle,
+    /// Timing of the parse stage, only present if the solution declares one.
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
-/// Represents benchmark times for a set of days.
+/// Represents the benchmark time of a single part or the parse stage.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    /// Formatted (mean) execution time, e.g. `1.5µs`.
+    pub duration: String,
+    /// Benchmark statistics, not present for timings stored before they were recorded.
+    pub stats: Option<BenchStats>,
+}
+
+impl Timing {
+    /// Returns the timing of a stage, if it was benched.
+    pub fn stage(&self, stage: Stage) -> Option<&PartTiming> {
+        match stage {
+            Stage::Parse => self.parse.as_ref(),
+            Stage::PartOne => self.part_1.as_ref(),
+            Stage::PartTwo => self.part_2.as_ref(),
+        }
+    }
+}
+
+/// A benched stage of a solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::PartOne, Stage::PartTwo];
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "Parse"),
+            Stage::PartOne => write!(f, "Part 1"),
+            Stage::PartTwo => write!(f, "Part 2"),
+        }
+    }
+}
+
+impl From<&str> for PartTiming {
+    fn from(value: &str) -> Self {
+        PartTiming {
+            duration: value.into(),
+            stats: None,
+        }
+    }
+}
+
+/// A stored benchmark run, see [`Timings::history`].
+#[derive(Clone, Debug)]
+pub struct TimingRun {
+    /// The git commit the run was benched on, suffixed with `-dirty` if there were uncommitted changes.
+    pub commit: Option<String>,
+    /// UTC date of the run, e.g. `2025-12-01T05:00:00Z`.
+    pub date: String,
+    /// Timings of the puzzles benched in this run.
+    pub data: Vec<Timing>,
+}
+
+impl TimingRun {
+    /// Creates a run from the given timings, keyed by the current git commit and date.
+    pub fn now(data: Vec<Timing>) -> Self {
+        TimingRun {
+            commit: git_commit(),
+            date: current_date(),
+            data,
+        }
+    }
+}
+
+/// Returns the current UTC date, e.g. `2025-12-01T05:00:00Z`.
+pub fn current_date() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    format_date(secs)
+}
+
+/// Returns the short hash of the checked out commit, or `None` if git is not available.
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let is_dirty = Command::new("git")
+        .args(["status", "--porcelain"])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty());
+
+    Some(if is_dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+/// Formats seconds since the unix epoch as an UTC date, e.g. `2025-12-01T05:00:00Z`.
+fn format_date(secs: u64) -> String {
+    let days = secs / 86_400;
+    let time = secs % 86_400;
+
+    // see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+/// Represents benchmark times for a set of puzzles, possibly spanning several years.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
+    /// The latest timings per puzzle.
     pub data: Vec<Timing>,
+    /// Every stored benchmark run, oldest first.
+    pub history: Vec<TimingRun>,
 }
 
 impl Timings {
@@ -46,13 +190,34 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.puzzle == timing.puzzle) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
-        Timings { data }
+        data.sort_unstable_by_key(|a| a.puzzle);
+        Timings {
+            data,
+            history: self.history.clone(),
+        }
+    }
+
+    /// Appends a benchmark run to the history.
+    pub fn record(&mut self, run: TimingRun) {
+        self.history.push(run);
+    }
+
+    /// Returns the most recent stored timing of a stage that has benchmark statistics.
+    /// Looks through the history first, then falls back to the latest timings.
+    pub fn last_stage_timing(&self, puzzle: Puzzle, stage: Stage) -> Option<&PartTiming> {
+        self.history
+            .iter()
+            .rev()
+            .flat_map(|run| run.data.iter())
+            .chain(self.data.iter())
+            .filter(|timing| timing.puzzle == puzzle)
+            .filter_map(|timing| timing.stage(stage))
+            .find(|timing| timing.stats.is_some())
     }
 
     /// Sum up total duration of timings as millis.
@@ -60,10 +225,10 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    pub fn is_day_complete(&self, puzzle: Puzzle) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.puzzle == puzzle && t.part_1.is_some() && t.part_2.is_some())
     }
 }
 
@@ -78,6 +243,11 @@ impl From<Timings> for JsonValue {
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
         );
 
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -88,19 +258,91 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        // NOTE: timings stored before the history was introduced do not have this key.
+        let json_history = match json.get("history") {
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?
+                .as_slice(),
+            None => &[],
+        };
+
         Ok(Timings {
             data: json_data
                 .iter()
                 .map(Timing::try_from)
                 .collect::<Result<_, _>>()?,
+            history: json_history
+                .iter()
+                .map(TimingRun::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&TimingRun> for JsonValue {
+    fn from(value: &TimingRun) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "commit".into(),
+            match &value.commit {
+                Some(commit) => JsonValue::String(commit.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("date".into(), JsonValue::String(value.date.clone()));
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for TimingRun {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing run to be a JSON object.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected timing run.commit to be null or string.")?;
+
+        let date = json
+            .get("date")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected timing run.date to be a string.")?;
+
+        let data = json
+            .get("data")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected timing run.data to be an array.")?;
+
+        Ok(TimingRun {
+            commit: commit.cloned(),
+            date: date.clone(),
+            data: data
+                .iter()
+                .map(Timing::try_from)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -111,24 +353,32 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year().to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day().to_string()),
+        );
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        if let Some(parse) = &value.parse {
+            map.insert("parse".into(), JsonValue::from(parse));
+        }
 
         map.insert(
             "part_1".into(),
-            match part_1 {
-                Some(x) => x,
+            match &value.part_1 {
+                Some(x) => JsonValue::from(x),
                 None => JsonValue::Null,
             },
         );
 
         map.insert(
             "part_2".into(),
-            match part_2 {
-                Some(x) => x,
+            match &value.part_2 {
+                Some(x) => JsonValue::from(x),
                 None => JsonValue::Null,
             },
         );
@@ -145,21 +395,50 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
+        // NOTE: timings stored before multi-year support do not have a year, assume the configured one.
+        let year = match json.get("year") {
+            Some(v) => v
+                .get::<String>()
+                .and_then(|year| Year::from_str(year).ok())
+                .ok_or("Expected timing.year to be a Year struct.")?,
+            None => Year::configured(),
+        };
+
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
+        let puzzle = Puzzle::new(year, day).ok_or("Expected timing.day to be part of the year.")?;
+
+        // NOTE: timings of solutions without a parse stage do not have this key.
+        let parse = match json.get("parse") {
+            Some(v) if !v.is_null() => Some(PartTiming::try_from(v)?),
+            _ => None,
+        };
+
         let part_1 = json
             .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+            .map(|v| {
+                if v.is_null() {
+                    Ok(None)
+                } else {
+                    PartTiming::try_from(v).map(Some)
+                }
+            })
+            .ok_or("Expected timing.part_1 to be null, string or object.")??;
 
         let part_2 = json
             .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+            .map(|v| {
+                if v.is_null() {
+                    Ok(None)
+                } else {
+                    PartTiming::try_from(v).map(Some)
+                }
+            })
+            .ok_or("Expected timing.part_2 to be null, string or object.")??;
 
         let total_nanos = json
             .get("total_nanos")
@@ -167,9 +446,10 @@ impl TryFrom<&JsonValue> for Timing {
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
         Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            puzzle,
+            parse,
+            part_1,
+            part_2,
             total_nanos,
         })
     }
@@ -177,9 +457,51 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        // NOTE: parts without stats keep the plain string format of older timings.
+        let Some(stats) = &value.stats else {
+            return JsonValue::String(value.duration.clone());
+        };
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("duration".into(), JsonValue::String(value.duration.clone()));
+        map.insert("stats".into(), JsonValue::from(stats));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        if let Some(duration) = value.get::<String>() {
+            return Ok(PartTiming::from(duration.as_str()));
+        }
+
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a string or JSON object.")?;
+
+        let duration = json
+            .get("duration")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected part timing.duration to be a string.")?;
+
+        let stats = json.get("stats").map(BenchStats::try_from).transpose()?;
+
+        Ok(PartTiming {
+            duration: duration.clone(),
+            stats,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::puzzle;
 
     use super::{Timing, Timings};
 
@@ -187,29 +509,38 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
+                    puzzle: puzzle!(2025, 2),
+                    parse: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
+                    puzzle: puzzle!(2025, 4),
+                    parse: None,
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
                 },
             ],
+            history: vec![],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use std::time::Duration;
+
+        use crate::{
+            day, puzzle,
+            template::{Puzzle, Year, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
@@ -217,12 +548,42 @@ mod tests {
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
-            assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(
+                timing.puzzle,
+                Puzzle::new(Year::configured(), day!(1)).unwrap()
+            );
+            assert_eq!(timing.part_1, Some("1ms".into()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_year() {
+            let json = r#"{ "data": [{ "year": "2024", "day": "20", "part_1": "1ms", "part_2": "2ms", "total_nanos": 3000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].puzzle, puzzle!(2024, 20));
+        }
+
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": { "duration": "1.5µs", "stats": { "warmup": 10, "samples": 100, "mean_nanos": 1500, "median_nanos": 1400, "min_nanos": 1000, "p95_nanos": 2000, "stddev_nanos": 100, "outliers": 3 } }, "part_2": "2ms", "total_nanos": 2001500 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let part_1 = timings.data[0].part_1.as_ref().unwrap();
+            assert_eq!(part_1.duration, "1.5µs");
+            let stats = part_1.stats.unwrap();
+            assert_eq!(stats.median, Duration::from_nanos(1400));
+            assert_eq!(stats.outliers, 3);
+            assert_eq!(timings.data[0].part_2, Some("2ms".into()));
+            assert_eq!(timings.data[0].parse, None);
+        }
+
+        #[test]
+        fn handles_json_timings_with_parse_stage() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "parse": "3ms", "part_1": "1ms", "part_2": "2ms", "total_nanos": 6000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].parse, Some("3ms".into()));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -245,6 +606,46 @@ mod tests {
         }
     }
 
+    mod history {
+        use crate::{
+            puzzle,
+            template::timings::{Stage, Timings, format_date},
+        };
+
+        #[test]
+        fn handles_json_history() {
+            let json = r#"{ "data": [], "history": [{ "commit": "abc1234", "date": "2025-12-01T05:00:00Z", "data": [{ "year": "2025", "day": "01", "part_1": { "duration": "1.5µs", "stats": { "warmup": 10, "samples": 100, "mean_nanos": 1500, "median_nanos": 1400, "min_nanos": 1000, "p95_nanos": 2000, "stddev_nanos": 100, "outliers": 3 } }, "part_2": "2ms", "total_nanos": 2001500 }] }, { "commit": null, "date": "2025-12-02T05:00:00Z", "data": [] }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.history.len(), 2);
+            assert_eq!(timings.history[0].commit.as_deref(), Some("abc1234"));
+            assert_eq!(timings.history[1].commit, None);
+
+            let part_1 = timings.last_stage_timing(puzzle!(2025, 1), Stage::PartOne);
+            assert_eq!(part_1.unwrap().duration, "1.5µs");
+            // stages without stats can not serve as a baseline.
+            assert!(
+                timings
+                    .last_stage_timing(puzzle!(2025, 1), Stage::PartTwo)
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn keeps_history_when_merging() {
+            let json = r#"{ "data": [], "history": [{ "commit": "abc1234", "date": "2025-12-01T05:00:00Z", "data": [] }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let merged = timings.merge(&Timings::default());
+            assert_eq!(merged.history.len(), 1);
+        }
+
+        #[test]
+        fn formats_dates() {
+            assert_eq!(format_date(0), "1970-01-01T00:00:00Z");
+            assert_eq!(format_date(1_764_565_200), "2025-12-01T05:00:00Z");
+            assert_eq!(format_date(951_782_400), "2000-02-29T00:00:00Z");
+        }
+    }
+
     mod serialization {
         use super::get_mock_timings;
         use std::collections::HashMap;
@@ -270,7 +671,7 @@ mod tests {
 
     mod is_day_complete {
         use crate::{
-            day,
+            puzzle,
             template::timings::{Timing, Timings},
         };
 
@@ -278,48 +679,54 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(puzzle!(2025, 1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle!(2025, 1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2025, 1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle!(2025, 1)), false);
         }
     }
 
     mod merge {
         use crate::{
-            day,
+            puzzle,
             template::timings::{Timing, Timings},
         };
 
@@ -330,18 +737,20 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(3),
+                    puzzle: puzzle!(2025, 3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[2].day, day!(3));
-            assert_eq!(merged.data[3].day, day!(4));
+            assert_eq!(merged.data[0].puzzle, puzzle!(2025, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2025, 2));
+            assert_eq!(merged.data[2].puzzle, puzzle!(2025, 3));
+            assert_eq!(merged.data[3].puzzle, puzzle!(2025, 4));
         }
 
         #[test]
@@ -350,19 +759,21 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
+                    puzzle: puzzle!(2025, 2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[0].puzzle, puzzle!(2025, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2025, 2));
             assert_eq!(merged.data[1].total_nanos, 0_f64);
-            assert_eq!(merged.data[2].day, day!(4));
+            assert_eq!(merged.data[2].puzzle, puzzle!(2025, 4));
         }
 
         #[test]
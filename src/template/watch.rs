@@ -0,0 +1,281 @@
+/// Module that watches the files of a puzzle and re-runs its tests and solution whenever one of them changes.
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Puzzle,
+    compare::Comparison,
+    run_multi::{child_commands, get_path_for_bin},
+    runner::{InputSource, RunResult},
+    timings::Stage,
+};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Re-runs the tests and the solution of a puzzle on the given input whenever its files change. Runs until interrupted.
+pub fn watch(puzzle: Puzzle, is_release: bool, input: &InputSource) -> ! {
+    let mut previous: Option<RunResult> = None;
+
+    loop {
+        let snapshot = snapshot(puzzle, input);
+
+        println!("{ANSI_BOLD}Running {puzzle}...{ANSI_RESET}");
+        run_tests(puzzle, is_release);
+
+        match child_commands::run_solution(puzzle, input, false, is_release, None) {
+            Ok(result) if !result.parts.is_empty() => {
+                if let Some(previous) = &previous {
+                    print_diff(&diff(puzzle, previous, &result));
+                }
+                previous = Some(result);
+            }
+            Ok(_) => {}
+            // e.g. a compile error, keep diffing against the last successful run.
+            Err(e) => eprintln!("Failed to run {puzzle}: {e}"),
+        }
+
+        println!("---");
+        println!("👀 Watching for changes, press Ctrl+C to stop.");
+
+        while self::snapshot(puzzle, input) == snapshot {
+            thread::sleep(POLL_INTERVAL);
+        }
+        // give editors a moment to finish writing.
+        thread::sleep(POLL_INTERVAL);
+        println!();
+    }
+}
+
+/// Returns the files that affect a puzzle: its binary, the library and template sources, its examples
+/// and the input file, if one was given.
+fn watched_files(puzzle: Puzzle, input: &InputSource) -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(get_path_for_bin(puzzle))];
+    if let InputSource::File(path) = input {
+        files.push(path.into());
+    }
+    // the binaries of other days don't affect this one.
+    collect_sources(Path::new("src"), Path::new("src/bin"), &mut files);
+
+    let examples_dir = PathBuf::from("data/examples").join(puzzle.year().to_string());
+    let day = puzzle.day().to_string();
+
+    if let Ok(entries) = fs::read_dir(&examples_dir) {
+        files.extend(
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| {
+                    path.file_name()
+                        .is_some_and(|name| name.to_string_lossy().starts_with(&day))
+                }),
+        );
+    }
+
+    files
+}
+
+/// Collects the `.rs` files in a directory and its subdirectories, skipping the directory `exclude`.
+fn collect_sources(dir: &Path, exclude: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.filter_map(|entry| Some(entry.ok()?.path())) {
+        if path.is_dir() {
+            if path != exclude {
+                collect_sources(&path, exclude, files);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// Returns the modification time of every watched file. Added and removed files change the snapshot, too.
+fn snapshot(puzzle: Puzzle, input: &InputSource) -> HashMap<PathBuf, Option<SystemTime>> {
+    watched_files(puzzle, input)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Runs the tests of a puzzle, only printing their output if they fail.
+fn run_tests(puzzle: Puzzle, is_release: bool) {
+    let bin_name = puzzle.to_string();
+    let mut args = vec!["test", "--quiet", "--bin", &bin_name];
+    if is_release {
+        args.push("--release");
+    }
+
+    match Command::new("cargo").args(&args).output() {
+        Ok(output) if output.status.success() => println!("Tests: ✔ passed"),
+        Ok(output) => {
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("Tests: ✖ failed");
+        }
+        Err(e) => eprintln!("Failed to run tests: {e}"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The change of a stage between two runs.
+#[derive(Clone, Debug, PartialEq)]
+struct Change {
+    comparison: Comparison,
+    /// The previous and current answer, if the answer changed.
+    answer: Option<(Option<String>, Option<String>)>,
+}
+
+/// Compares the stages of two runs. Stages that are missing from either run are skipped.
+fn diff(puzzle: Puzzle, previous: &RunResult, current: &RunResult) -> Vec<Change> {
+    let parse = previous
+        .parse
+        .as_ref()
+        .zip(current.parse.as_ref())
+        .map(|(before, after)| Change {
+            comparison: Comparison {
+                puzzle,
+                stage: Stage::Parse,
+                before: before.duration,
+                after: after.duration,
+            },
+            answer: None,
+        });
+
+    let parts = current.parts.iter().filter_map(|after| {
+        let before = previous.parts.iter().find(|p| p.part == after.part)?;
+        let stage = if after.part == 1 {
+            Stage::PartOne
+        } else {
+            Stage::PartTwo
+        };
+
+        Some(Change {
+            comparison: Comparison {
+                puzzle,
+                stage,
+                before: before.duration,
+                after: after.duration,
+            },
+            answer: (before.answer != after.answer)
+                .then(|| (before.answer.clone(), after.answer.clone())),
+        })
+    });
+
+    parse.into_iter().chain(parts).collect()
+}
+
+fn print_diff(changes: &[Change]) {
+    if changes.is_empty() {
+        return;
+    }
+
+    let format_answer = |answer: &Option<String>| answer.as_deref().unwrap_or("✖").to_string();
+
+    println!();
+    println!("{ANSI_BOLD}Compared to the previous run{ANSI_RESET}");
+    for change in changes {
+        let answer = match &change.answer {
+            Some((before, after)) => {
+                format!("{} -> {}", format_answer(before), format_answer(after))
+            }
+            None => "unchanged".into(),
+        };
+        let comparison = &change.comparison;
+
+        println!(
+            "{:<6} {:<24} {:>10.1?} -> {:>10.1?} ({:+.1}%)",
+            comparison.stage.to_string(),
+            answer,
+            comparison.before,
+            comparison.after,
+            comparison.change()
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{diff, watched_files};
+    use crate::{
+        puzzle,
+        template::{
+            runner::{InputSource, ParseResult, PartResult, PartStatus, RunResult},
+            timings::Stage,
+        },
+    };
+
+    #[test]
+    fn watches_sources_and_input() {
+        let files = watched_files(puzzle!(2025, 4), &InputSource::File("day4.txt".into()));
+        let watched = |path: &str| files.iter().any(|file| file.ends_with(path));
+
+        assert!(watched("src/bin/2025-04.rs"));
+        assert!(watched("day4.txt"));
+        assert!(watched("src/lib.rs"));
+        assert!(watched("src/grid.rs"));
+        assert!(watched("src/template/runner.rs"));
+        assert!(!watched("src/bin/2025-05.rs"));
+        assert!(!watched("src/templates/grid.txt"));
+    }
+
+    fn result(answers: &[Option<&str>], nanos: u64) -> RunResult {
+        let puzzle = puzzle!(2025, 7);
+        RunResult {
+            parse: Some(ParseResult {
+                puzzle,
+                duration: Duration::from_nanos(nanos),
+                stats: None,
+            }),
+            parts: answers
+                .iter()
+                .enumerate()
+                .map(|(i, answer)| PartResult {
+                    puzzle,
+                    part: i as u8 + 1,
+                    answer: answer.map(Into::into),
+                    status: PartStatus::of(answer),
+                    duration: Duration::from_nanos(nanos),
+                    stats: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn diffs_answers_and_timings() {
+        let previous = result(&[Some("21"), None], 100);
+        let current = result(&[Some("21"), Some("40")], 50);
+        let changes = diff(puzzle!(2025, 7), &previous, &current);
+
+        let stages: Vec<_> = changes.iter().map(|c| c.comparison.stage).collect();
+        assert_eq!(stages, vec![Stage::Parse, Stage::PartOne, Stage::PartTwo]);
+
+        assert_eq!(changes[1].answer, None);
+        assert_eq!(changes[2].answer, Some((None, Some("40".into()))));
+        assert_eq!(changes[2].comparison.change(), -50.0);
+    }
+
+    #[test]
+    fn skips_new_parts() {
+        let previous = result(&[Some("21")], 100);
+        let current = result(&[Some("21"), Some("40")], 100);
+        assert_eq!(diff(puzzle!(2025, 7), &previous, &current).len(), 2);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,167 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{AllDays, all_days};
+
+/// The first year advent of code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// The most recent event year, used when `AOC_YEAR` is not configured.
+const LATEST_YEAR: u16 = 2025;
+
+/// The event year configured via the `AOC_YEAR` environment variable at compile time.
+const CONFIGURED_YEAR: Option<u16> = parse_year(option_env!("AOC_YEAR"));
+
+/// Parses a year in a const context, returns [`None`] if the value is missing or malformed.
+const fn parse_year(value: Option<&str>) -> Option<u16> {
+    let Some(value) = value else {
+        return None;
+    };
+
+    let bytes = value.as_bytes();
+    if bytes.is_empty() || bytes.len() > 4 {
+        return None;
+    }
+
+    let mut year: u16 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        year = year * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+
+    Some(year)
+}
+
+/// A valid advent of code event year (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Returns the event year configured via `AOC_YEAR` in `.cargo/config.toml`.
+    /// Falls back to the most recent event if the variable is not set.
+    pub const fn configured() -> Self {
+        match CONFIGURED_YEAR {
+            Some(year) if year >= FIRST_YEAR => Self(year),
+            _ => Self(LATEST_YEAR),
+        }
+    }
+
+    /// Returns the number of puzzles released for this event.
+    /// Starting with 2025, advent of code runs for 12 days instead of 25.
+    pub const fn days(self) -> u8 {
+        if self.0 >= 2025 { 12 } else { 25 }
+    }
+
+    /// Returns an iterator over every day of this event.
+    pub fn all_days(self) -> AllDays {
+        all_days(self)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub const fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year of {FIRST_YEAR} or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value of 2015 or later")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Year, parse_year};
+
+    #[test]
+    fn days_per_year() {
+        assert_eq!(Year(2015).days(), 25);
+        assert_eq!(Year(2024).days(), 25);
+        assert_eq!(Year(2025).days(), 12);
+    }
+
+    #[test]
+    fn parses_year() {
+        assert_eq!(parse_year(Some("2025")), Some(2025));
+        assert_eq!(parse_year(Some("20x5")), None);
+        assert_eq!(parse_year(Some("")), None);
+        assert_eq!(parse_year(None), None);
+    }
+
+    #[test]
+    fn rejects_years_before_first_event() {
+        assert!(Year::new(2014).is_none());
+        assert_eq!(Year::new(2015), Some(Year(2015)));
+        assert!("2014".parse::<Year>().is_err());
+        assert_eq!("2024".parse::<Year>().unwrap(), Year(2024));
+    }
+}
+
+/* -------------------------------------------------------------------------- */